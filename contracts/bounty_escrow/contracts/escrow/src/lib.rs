@@ -91,7 +91,7 @@
 mod events;
 mod test_bounty_escrow;
 
-use soroban_sdk::{contract, contracterror, contractimpl, contracttype, token, Address, Env, Map, String, Vec};
+use soroban_sdk::{contract, contractclient, contracterror, contractimpl, contracttype, symbol_short, token, Address, Bytes, BytesN, Env, Map, String, Vec};
 use events::{
     BountyEscrowInitialized, FundsLocked, FundsReleased, FundsRefunded,
     emit_bounty_initialized, emit_funds_locked, emit_funds_released, emit_funds_refunded
@@ -145,6 +145,72 @@ pub enum Error {
     
     /// Returned when metadata exceeds size limits
     MetadataTooLarge = 8,
+
+    /// Returned when a multisig operation is attempted on a bounty that was
+    /// not initialized with `init_multisig`
+    MultisigNotConfigured = 9,
+
+    /// Returned when an admin calls `approve_release` more than once for the
+    /// same bounty/contributor pair
+    AlreadyApproved = 10,
+
+    /// Returned when `claim_funds` is called on an escrow with no hashlock set
+    NoHashlock = 11,
+
+    /// Returned when `set_fee` is called with `fee_bps` greater than 10000 (100%)
+    FeeTooHigh = 12,
+
+    /// Returned when a bounty-mutating call re-enters itself mid-execution
+    /// (e.g. via a malicious token contract calling back into the escrow)
+    Reentrancy = 13,
+
+    /// Not returned by any entrypoint: `open_dispute` accepts any
+    /// `contributor` address that authorizes the call, since the contract
+    /// does not track an intended contributor
+    NotDisputeParty = 14,
+
+    /// Returned when `approve_dispute`/`reject_dispute` is called on a
+    /// bounty that isn't currently `Disputed`
+    NotDisputed = 15,
+
+    /// Returned when `recover` is called on a bounty with no `recovery`
+    /// address configured
+    RecoveryNotConfigured = 16,
+
+    /// Returned when `recover` is called before the inactivity window has
+    /// elapsed since the escrow's `last_activity`
+    RecoveryNotDue = 17,
+
+    /// Returned when a `ReleaseSchedule` lookup is attempted with a
+    /// `schedule_id` that doesn't exist for the bounty
+    ScheduleNotFound = 18,
+
+    /// Returned when `release_schedule_automatic` is called before a
+    /// schedule's `release_timestamp` has passed
+    ScheduleNotDue = 19,
+
+    /// Returned when a release/claim is attempted against a `ReleaseSchedule`
+    /// that has already paid out its full amount, or been terminated
+    ScheduleAlreadyReleased = 20,
+
+    /// Returned when `stake_locked_funds` is called on a bounty that's
+    /// already staked
+    AlreadyStaked = 21,
+
+    /// Returned when `unstake_locked_funds` is called on a bounty with no
+    /// active stake (release paths unwind an active stake themselves
+    /// rather than returning this)
+    NotStaked = 22,
+
+    /// Returned when `approve_schedule_release` is called before
+    /// `set_schedule_approvers` has configured an approver set
+    ScheduleApproversNotConfigured = 23,
+
+    /// Returned when `release_funds`/`release_schedule_manual` is called
+    /// directly on a bounty whose contract was initialized via
+    /// `init_multisig`/`set_schedule_approvers`; the quorum path
+    /// (`approve_release`/`approve_schedule_release`) must be used instead
+    MultisigRequired = 24,
 }
 
 // ============================================================================
@@ -172,6 +238,13 @@ pub enum Error {
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub enum EscrowStatus {
     Locked,
+    /// Some, but not all, of the escrowed amount has been released via
+    /// `release_partial`. `amount - released_amount` is still refundable.
+    Partial,
+    /// The depositor or contributor has contested the bounty via
+    /// `open_dispute`. Only the escrow's `approver` can move it out of this
+    /// state, via `approve_dispute` or `reject_dispute`.
+    Disputed,
     Released,
     Refunded,
 }
@@ -204,6 +277,28 @@ pub struct Escrow {
     pub amount: i128,
     pub status: EscrowStatus,
     pub deadline: u64,
+    /// Optional SHA-256 hashlock (HTLC-style). When set, `claim_funds` can
+    /// release the escrow to the contributor without admin auth as long as
+    /// the caller supplies the matching preimage while the escrow is still
+    /// `Locked`; `deadline` itself is not checked by `claim_funds`.
+    pub hashlock: Option<BytesN<32>>,
+    /// Cumulative amount already paid out via `release_partial`/`release_funds`.
+    /// `amount - released_amount` is what remains refundable after `deadline`.
+    pub released_amount: i128,
+    /// Optional third-party arbiter. When set, `release_funds` must be
+    /// authorized by this address instead of the admin, and a `Disputed`
+    /// escrow can only be resolved by this address calling `approve_dispute`
+    /// or `reject_dispute`.
+    pub approver: Option<Address>,
+    /// Optional social-recovery address. If set, `recover` can send the
+    /// locked funds here once `last_activity` has been stale for longer
+    /// than the configured recovery window, protecting against the
+    /// depositor losing key access before `deadline`.
+    pub recovery: Option<Address>,
+    /// Ledger timestamp of the most recent depositor-authorized action on
+    /// this escrow (lock, `set_recovery_address`, or an `open_dispute` the
+    /// depositor initiated). `recover` measures inactivity from this value.
+    pub last_activity: u64,
 }
 
 /// Metadata structure for enhanced escrow indexing and categorization.
@@ -285,6 +380,9 @@ pub struct EscrowWithMetadata {
 /// * `Token` - Stores the token contract address (instance storage)
 /// * `Escrow(u64)` - Stores escrow data indexed by bounty_id (persistent storage)
 /// * `EscrowMetadata(u64)` - Stores metadata for bounty_id (persistent storage)
+/// * `Admins` - Stores the multisig admin set, when `init_multisig` is used (instance storage)
+/// * `Threshold` - Stores the number of approvals required to release funds (instance storage)
+/// * `Approvals(u64)` - Stores accumulated release approvals for a bounty (persistent storage)
 ///
 /// # Storage Types
 /// - **Instance Storage**: Admin and Token (never expires, tied to contract)
@@ -295,6 +393,240 @@ pub enum DataKey {
     Token,
     Escrow(u64), // bounty_id
     EscrowMetadata(u64), // bounty_id
+    Admins,
+    Threshold,
+    Approvals(u64), // bounty_id
+    FeeBps,
+    FeeCollector,
+    /// Index of bounty IDs currently in a given `EscrowStatus` (persistent storage)
+    StatusIndex(EscrowStatus),
+    /// Cross-call reentrancy guard for a bounty (temporary storage)
+    Lock(u64),
+    /// Milestone schedule for a bounty, when set up via `create_milestones`
+    /// (persistent storage)
+    Milestones(u64), // bounty_id
+    /// Append-only log of fund movements for a bounty (persistent storage)
+    History(u64), // bounty_id
+    /// Configured inactivity window (seconds) for `recover`, when set via
+    /// `set_recovery_window` (instance storage); defaults to
+    /// `DEFAULT_RECOVERY_WINDOW` if never configured.
+    RecoveryWindow,
+    /// Next auto-assigned bounty ID handed out by `EscrowBuilder::build` (instance storage)
+    NextBountyId,
+    /// Admin-managed release schedules for a bounty, set up via
+    /// `create_release_schedule`/`create_vesting_schedule` (persistent storage)
+    Schedules(u64), // bounty_id
+    /// Append-only log of `ReleaseSchedule` payouts for a bounty (persistent storage)
+    ScheduleHistory(u64), // bounty_id
+    /// Active `StakeInfo` for a bounty, set by `stake_locked_funds` and
+    /// cleared by `unstake_locked_funds` (persistent storage)
+    Stake(u64), // bounty_id
+    /// Set of addresses authorized to approve early `ReleaseSchedule`
+    /// releases, registered via `set_schedule_approvers` (instance storage).
+    /// Separate from the fund-locking `Admin`/`Admins`.
+    ScheduleApprovers,
+    /// Number of distinct `ScheduleApprovers` signatures required to release
+    /// a schedule early via `approve_schedule_release` (instance storage)
+    ScheduleApprovalThreshold,
+    /// Accumulated `ScheduleApprovalState` for one schedule's early release
+    /// (persistent storage)
+    ScheduleApprovals(u64, u32), // (bounty_id, schedule_id)
+}
+
+/// Default inactivity window `recover` waits for when the admin has not
+/// called `set_recovery_window`: 90 days.
+const DEFAULT_RECOVERY_WINDOW: u64 = 90 * 24 * 60 * 60;
+
+/// Exhaustive, compile-time list of `EscrowStatus` variants. Iterating this
+/// array (rather than hand-maintained per-status match arms) is what lets
+/// `counts_by_status()` automatically cover any status added in the future.
+const ALL_ESCROW_STATUSES: [EscrowStatus; 5] = [
+    EscrowStatus::Locked,
+    EscrowStatus::Partial,
+    EscrowStatus::Disputed,
+    EscrowStatus::Released,
+    EscrowStatus::Refunded,
+];
+
+/// Tracks admin approvals collected towards releasing a specific bounty to a
+/// specific contributor.
+///
+/// Approvals are scoped to the `contributor` they were gathered for: if the
+/// intended recipient changes, previously collected approvals are discarded
+/// so an admin's signature for one recipient can never be reused for another.
+///
+/// # Fields
+/// * `contributor` - The recipient these approvals authorize a release to
+/// * `approvers` - Distinct admin addresses that have approved so far
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ApprovalState {
+    pub contributor: Address,
+    pub approvers: Vec<Address>,
+}
+
+/// A single scheduled payment within a bounty's milestone release schedule.
+///
+/// # Fields
+/// * `amount` - Amount to pay out when this milestone is released
+/// * `deadline` - Informational target timestamp for this milestone (not
+///   enforced by `release_milestone`; the admin releases on completion)
+/// * `released` - Whether this milestone has already been paid out
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Milestone {
+    pub amount: i128,
+    pub deadline: u64,
+    pub released: bool,
+}
+
+/// The kind of fund movement recorded in a bounty's `EscrowEvent` history.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum EscrowEventKind {
+    Deposit,
+    Release,
+    Refund,
+    /// Locked balance deposited into an external staking pool via `stake_locked_funds`
+    Staked,
+    /// Principal (plus any accrued yield routed separately) withdrawn back from the pool via `unstake_locked_funds`
+    Unstaked,
+}
+
+/// A single entry in a bounty's on-chain transaction history, as returned by
+/// `get_history`/`get_history_page`.
+///
+/// # Fields
+/// * `kind` - The type of fund movement
+/// * `amount` - Amount moved in this entry
+/// * `actor` - Address that initiated or received the movement
+/// * `timestamp` - Ledger timestamp when the entry was recorded
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EscrowEvent {
+    pub kind: EscrowEventKind,
+    pub amount: i128,
+    pub actor: Address,
+    pub timestamp: u64,
+}
+
+/// How a `ReleaseSchedule` payout in `get_release_history` was triggered.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ReleaseType {
+    /// Paid out by anyone via `release_schedule_automatic` once due.
+    Automatic,
+    /// Forced early by the admin via `release_schedule_manual`.
+    Manual,
+    /// Paid out incrementally by the recipient via `claim_vested`.
+    Vested,
+    /// Cancelled early via `terminate_schedule`; `amount`/`recipient` on
+    /// this entry describe the portion refunded to the depositor. For a
+    /// vesting schedule with an outstanding vested balance, that balance is
+    /// paid out first and recorded as its own `Vested` entry.
+    Terminated,
+}
+
+/// A single admin-managed scheduled payout for a bounty, created via
+/// `create_release_schedule` (fixed-timestamp, all-or-nothing) or
+/// `create_vesting_schedule` (linear vesting with a cliff), and paid out via
+/// `release_schedule_automatic`/`release_schedule_manual`/`claim_vested`.
+///
+/// # Fields
+/// * `schedule_id` - 1-based identifier, unique within the bounty
+/// * `amount` - Total amount this schedule releases once fully due/vested
+/// * `release_timestamp` - For a fixed schedule, the timestamp at which the
+///   full `amount` becomes claimable; mirrors `end_ts` for a vesting schedule
+/// * `recipient` - Address entitled to this schedule's payout
+/// * `released` - Whether the schedule has paid out its full `amount`
+/// * `released_at` / `released_by` - When and by whom the schedule was last
+///   paid out (the most recent `claim_vested` call, for vesting schedules)
+/// * `is_vesting` - Whether this is a `create_vesting_schedule` schedule
+///   rather than a fixed-timestamp one
+/// * `start_ts` / `end_ts` - Vesting window; unused (zero) for fixed schedules
+/// * `cliff_ts` - Nothing is claimable before this timestamp; unused (zero)
+///   for fixed schedules
+/// * `step_seconds` - Vesting accrues in discrete buckets of this size;
+///   unused (zero) for fixed schedules
+/// * `released_amount` - Cumulative amount already paid out from this
+///   schedule; equals `amount` once `released` is true
+/// * `terminated` - Set by `terminate_schedule`; once true, no further
+///   `release_schedule_automatic`/`release_schedule_manual`/`claim_vested`
+///   calls are accepted for this schedule
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ReleaseSchedule {
+    pub schedule_id: u32,
+    pub amount: i128,
+    pub release_timestamp: u64,
+    pub recipient: Address,
+    pub released: bool,
+    pub released_at: Option<u64>,
+    pub released_by: Option<Address>,
+    pub is_vesting: bool,
+    pub start_ts: u64,
+    pub end_ts: u64,
+    pub cliff_ts: u64,
+    pub step_seconds: u64,
+    pub released_amount: i128,
+    pub terminated: bool,
+}
+
+/// A single entry in a bounty's `ReleaseSchedule` payout history, as
+/// returned by `get_release_history`.
+///
+/// # Fields
+/// * `approvers` - For a `Manual` release reached via `approve_schedule_release`,
+///   the full set of schedule-approver signatures that authorized it; empty
+///   for every other release type
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ScheduleReleaseEvent {
+    pub schedule_id: u32,
+    pub amount: i128,
+    pub recipient: Address,
+    pub release_type: ReleaseType,
+    pub timestamp: u64,
+    pub approvers: Vec<Address>,
+}
+
+/// Accumulated signatures toward releasing a single `ReleaseSchedule` early,
+/// via `approve_schedule_release`, once `required_approvals` distinct
+/// schedule approvers have signed.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ScheduleApprovalState {
+    pub approvers: Vec<Address>,
+}
+
+/// Tracks a bounty's locked balance while it's deposited in an external
+/// staking pool via `stake_locked_funds`, until `unstake_locked_funds`
+/// withdraws it back.
+///
+/// # Fields
+/// * `pool` - Address of the external staking-pool contract
+/// * `principal` - Amount deposited; returned to the escrow's own balance
+///   on unstake, untouched by yield
+/// * `beneficiary` - Address credited with any yield earned above
+///   `principal` on unstake; defaults to the escrow's `depositor`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct StakeInfo {
+    pub pool: Address,
+    pub principal: i128,
+    pub beneficiary: Address,
+}
+
+/// Minimal interface expected of an external staking pool, invoked by
+/// `stake_locked_funds`/`unstake_locked_funds` via cross-contract call.
+#[contractclient(name = "StakingPoolClient")]
+pub trait StakingPoolTrait {
+    /// Deposits `amount` from `from` and begins staking it on `from`'s behalf.
+    fn deposit_and_stake(env: Env, from: Address, amount: i128);
+
+    /// Withdraws `from`'s entire staked position (principal plus any
+    /// accrued rewards) to `to`, returning the total amount withdrawn.
+    fn withdraw_all(env: Env, from: Address, to: Address) -> i128;
 }
 
 // ============================================================================
@@ -365,6 +697,277 @@ fn validate_metadata_size(env: &Env, metadata: &EscrowMetadata) -> bool {
     serialized_size <= 1024
 }
 
+/// Splits `amount` into `(net, fee)` according to the configured protocol
+/// fee, if any. Returns `(amount, 0)` when no fee is configured.
+///
+/// # Parameters
+/// * `env` - The contract environment
+/// * `amount` - The gross amount being released
+fn split_fee(env: &Env, amount: i128) -> (i128, i128) {
+    if !env.storage().instance().has(&DataKey::FeeBps) {
+        return (amount, 0);
+    }
+    let fee_bps: u32 = env.storage().instance().get(&DataKey::FeeBps).unwrap();
+    let fee = amount * (fee_bps as i128) / 10000;
+    (amount - fee, fee)
+}
+
+/// Appends `bounty_id` to the per-status index for `status`.
+fn index_add(env: &Env, status: &EscrowStatus, bounty_id: u64) {
+    let key = DataKey::StatusIndex(status.clone());
+    let mut ids: Vec<u64> = env.storage().persistent().get(&key).unwrap_or(Vec::new(env));
+    ids.push_back(bounty_id);
+    env.storage().persistent().set(&key, &ids);
+}
+
+/// Removes `bounty_id` from the per-status index for `status`, if present.
+fn index_remove(env: &Env, status: &EscrowStatus, bounty_id: u64) {
+    let key = DataKey::StatusIndex(status.clone());
+    let ids: Vec<u64> = env.storage().persistent().get(&key).unwrap_or(Vec::new(env));
+    let mut remaining = Vec::new(env);
+    for id in ids.iter() {
+        if id != bounty_id {
+            remaining.push_back(id);
+        }
+    }
+    env.storage().persistent().set(&key, &remaining);
+}
+
+/// Moves `bounty_id` from the `old_status` index to the `new_status` index.
+/// A no-op when the status did not actually change.
+fn index_transition(env: &Env, bounty_id: u64, old_status: &EscrowStatus, new_status: &EscrowStatus) {
+    if old_status == new_status {
+        return;
+    }
+    index_remove(env, old_status, bounty_id);
+    index_add(env, new_status, bounty_id);
+}
+
+/// Sets the cross-call reentrancy guard for `bounty_id`, failing if it is
+/// already held (i.e. this bounty's mutating entrypoint is already
+/// executing somewhere up the call stack).
+fn guard_enter(env: &Env, bounty_id: u64) -> Result<(), Error> {
+    let key = DataKey::Lock(bounty_id);
+    if env.storage().temporary().has(&key) {
+        return Err(Error::Reentrancy);
+    }
+    env.storage().temporary().set(&key, &true);
+    Ok(())
+}
+
+/// Releases the reentrancy guard set by `guard_enter`.
+fn guard_exit(env: &Env, bounty_id: u64) {
+    env.storage().temporary().remove(&DataKey::Lock(bounty_id));
+}
+
+/// Hands out the next auto-assigned bounty ID for `EscrowBuilder::build`,
+/// starting at 1.
+fn next_bounty_id(env: &Env) -> u64 {
+    let id: u64 = env.storage().instance().get(&DataKey::NextBountyId).unwrap_or(1);
+    env.storage().instance().set(&DataKey::NextBountyId, &(id + 1));
+    id
+}
+
+/// Appends an entry to a bounty's audit trail, creating the log on first use.
+fn record_history(env: &Env, bounty_id: u64, kind: EscrowEventKind, amount: i128, actor: Address) {
+    let key = DataKey::History(bounty_id);
+    let mut history: Vec<EscrowEvent> = env
+        .storage()
+        .persistent()
+        .get(&key)
+        .unwrap_or_else(|| Vec::new(env));
+    history.push_back(EscrowEvent {
+        kind,
+        amount,
+        actor,
+        timestamp: env.ledger().timestamp(),
+    });
+    env.storage().persistent().set(&key, &history);
+}
+
+/// Finds the index of `schedule_id` within a bounty's schedule list.
+fn find_schedule_index(schedules: &Vec<ReleaseSchedule>, schedule_id: u32) -> Option<u32> {
+    for (i, schedule) in schedules.iter().enumerate() {
+        if schedule.schedule_id == schedule_id {
+            return Some(i as u32);
+        }
+    }
+    None
+}
+
+/// Computes the amount vested for a `create_vesting_schedule` schedule at
+/// `now`, per `total_amount * floor((now - start_ts)/step_seconds) *
+/// step_seconds / (end_ts - start_ts)`, clamped to `schedule.amount`.
+/// Returns 0 before `cliff_ts`.
+fn vested_amount(now: u64, schedule: &ReleaseSchedule) -> i128 {
+    if now < schedule.cliff_ts || now < schedule.start_ts {
+        return 0;
+    }
+    let elapsed = now - schedule.start_ts;
+    let steps = elapsed / schedule.step_seconds;
+    let vested_seconds = (steps * schedule.step_seconds) as i128;
+    let duration = (schedule.end_ts - schedule.start_ts) as i128;
+    (schedule.amount * vested_seconds / duration).min(schedule.amount)
+}
+
+/// Whether a `ReleaseSchedule` has a new claimable amount at `now`: for a
+/// fixed schedule, whether `release_timestamp` has passed; for a vesting
+/// schedule, whether `vested_amount` exceeds what's already been claimed.
+fn schedule_is_due(now: u64, schedule: &ReleaseSchedule) -> bool {
+    if schedule.released || schedule.terminated {
+        return false;
+    }
+    if schedule.is_vesting {
+        vested_amount(now, schedule) > schedule.released_amount
+    } else {
+        now >= schedule.release_timestamp
+    }
+}
+
+/// Appends an entry to a bounty's `ReleaseSchedule` payout history.
+fn record_schedule_release(
+    env: &Env,
+    bounty_id: u64,
+    schedule_id: u32,
+    amount: i128,
+    recipient: Address,
+    release_type: ReleaseType,
+    approvers: Vec<Address>,
+) {
+    let key = DataKey::ScheduleHistory(bounty_id);
+    let mut history: Vec<ScheduleReleaseEvent> = env
+        .storage()
+        .persistent()
+        .get(&key)
+        .unwrap_or_else(|| Vec::new(env));
+    history.push_back(ScheduleReleaseEvent {
+        schedule_id,
+        amount,
+        recipient,
+        release_type,
+        timestamp: env.ledger().timestamp(),
+        approvers,
+    });
+    env.storage().persistent().set(&key, &history);
+}
+
+/// Shared payout path for `release_schedule_automatic`/`release_schedule_manual`/
+/// `approve_schedule_release`. `forced_by: Some(addr)` (the admin, or the
+/// approver that completed the quorum) skips the
+/// due-timestamp check and records it as the `released_by`; `None` enforces
+/// the check and records `released_by` as the contract's own address.
+/// `approvers` is recorded on the `ScheduleReleaseEvent`; empty except when
+/// called from `approve_schedule_release`.
+fn pay_out_schedule(
+    env: Env,
+    bounty_id: u64,
+    schedule_id: u32,
+    forced_by: Option<Address>,
+    release_type: ReleaseType,
+    approvers: Vec<Address>,
+) -> Result<(), Error> {
+    if !env.storage().persistent().has(&DataKey::Schedules(bounty_id)) {
+        return Err(Error::BountyNotFound);
+    }
+    let mut schedules: Vec<ReleaseSchedule> =
+        env.storage().persistent().get(&DataKey::Schedules(bounty_id)).unwrap();
+    let index = find_schedule_index(&schedules, schedule_id).ok_or(Error::ScheduleNotFound)?;
+    let mut schedule = schedules.get(index).unwrap();
+
+    if schedule.is_vesting {
+        return Err(Error::ScheduleNotFound);
+    }
+    if schedule.released || schedule.terminated {
+        return Err(Error::ScheduleAlreadyReleased);
+    }
+
+    let now = env.ledger().timestamp();
+    if forced_by.is_none() && now < schedule.release_timestamp {
+        return Err(Error::ScheduleNotDue);
+    }
+
+    let mut escrow: Escrow = env.storage().persistent().get(&DataKey::Escrow(bounty_id)).unwrap();
+    if escrow.status != EscrowStatus::Locked && escrow.status != EscrowStatus::Partial {
+        return Err(Error::FundsNotLocked);
+    }
+
+    guard_enter(&env, bounty_id)?;
+
+    // Unwind any active stake first so the balance is on hand to release.
+    unstake_if_staked(&env, bounty_id)?;
+
+    let released_by = forced_by.unwrap_or_else(|| env.current_contract_address());
+    schedule.released = true;
+    schedule.released_at = Some(now);
+    schedule.released_by = Some(released_by);
+    schedule.released_amount = schedule.amount;
+    schedules.set(index, schedule.clone());
+    env.storage().persistent().set(&DataKey::Schedules(bounty_id), &schedules);
+
+    escrow.released_amount += schedule.amount;
+    let new_status = if escrow.released_amount == escrow.amount {
+        EscrowStatus::Released
+    } else {
+        EscrowStatus::Partial
+    };
+    index_transition(&env, bounty_id, &escrow.status, &new_status);
+    escrow.status = new_status;
+    env.storage().persistent().set(&DataKey::Escrow(bounty_id), &escrow);
+
+    let token_addr: Address = env.storage().instance().get(&DataKey::Token).unwrap();
+    let client = token::Client::new(&env, &token_addr);
+    client.transfer(&env.current_contract_address(), &schedule.recipient, &schedule.amount);
+
+    record_history(&env, bounty_id, EscrowEventKind::Release, schedule.amount, schedule.recipient.clone());
+    record_schedule_release(&env, bounty_id, schedule_id, schedule.amount, schedule.recipient.clone(), release_type, approvers);
+    emit_funds_released(
+        &env,
+        FundsReleased {
+            bounty_id,
+            amount: schedule.amount,
+            recipient: schedule.recipient,
+            timestamp: now,
+        },
+    );
+
+    guard_exit(&env, bounty_id);
+    Ok(())
+}
+
+/// Withdraws a bounty's `StakeInfo` (if any) back from its staking pool into
+/// the escrow's own balance, crediting any yield above `principal` to the
+/// stake's `beneficiary`. A no-op returning `Ok(0)` if nothing is staked.
+///
+/// Shared by `unstake_locked_funds` and by `release_funds`,
+/// `release_partial`, `claim_vested`, `release_due_schedules` and the
+/// fixed-schedule payout path, which call this first so a staked balance
+/// does not block those payouts. `approve_release`, `approve_dispute`,
+/// `reject_dispute`, `release_milestone`, `claim_funds`, `refund` and
+/// `recover` do not call it.
+fn unstake_if_staked(env: &Env, bounty_id: u64) -> Result<i128, Error> {
+    let key = DataKey::Stake(bounty_id);
+    if !env.storage().persistent().has(&key) {
+        return Ok(0);
+    }
+    let stake: StakeInfo = env.storage().persistent().get(&key).unwrap();
+    env.storage().persistent().remove(&key);
+
+    let pool_client = StakingPoolClient::new(env, &stake.pool);
+    let withdrawn = pool_client.withdraw_all(&env.current_contract_address(), &env.current_contract_address());
+
+    record_history(env, bounty_id, EscrowEventKind::Unstaked, withdrawn, stake.pool.clone());
+
+    let yield_amount = withdrawn - stake.principal;
+    if yield_amount > 0 {
+        let token_addr: Address = env.storage().instance().get(&DataKey::Token).unwrap();
+        let client = token::Client::new(env, &token_addr);
+        client.transfer(&env.current_contract_address(), &stake.beneficiary, &yield_amount);
+        record_history(env, bounty_id, EscrowEventKind::Release, yield_amount, stake.beneficiary.clone());
+    }
+
+    Ok(withdrawn)
+}
+
 // ============================================================================
 // Contract Implementation
 // ============================================================================
@@ -435,6 +1038,201 @@ impl BountyEscrowContract {
         Ok(())
     }
 
+    /// Initializes the contract with an M-of-N multisig admin set instead of
+    /// a single admin address.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `admins` - The set of addresses authorized to approve releases
+    /// * `threshold` - Number of distinct admin approvals required to release funds
+    /// * `token` - Token contract address for escrow payments
+    ///
+    /// # Returns
+    /// * `Ok(())` - Contract successfully initialized
+    /// * `Err(Error::AlreadyInitialized)` - Contract already initialized
+    ///
+    /// # State Changes
+    /// - Stores the admin set and threshold in instance storage
+    /// - Sets Token address in instance storage
+    ///
+    /// # Security Considerations
+    /// - Mutually exclusive with `init`; only one admin scheme can be active
+    /// - `threshold` should be greater than zero and not exceed `admins.len()`
+    /// - `release_funds` rejects direct calls once this is configured; use
+    ///   `approve_release`'s M-of-N quorum instead
+    pub fn init_multisig(
+        env: Env,
+        admins: Vec<Address>,
+        threshold: u32,
+        token: Address,
+    ) -> Result<(), Error> {
+        if env.storage().instance().has(&DataKey::Admin) {
+            return Err(Error::AlreadyInitialized);
+        }
+
+        env.storage().instance().set(&DataKey::Admins, &admins);
+        env.storage().instance().set(&DataKey::Threshold, &threshold);
+        env.storage().instance().set(&DataKey::Token, &token);
+        // Keep the single-admin slot populated with the first signer so
+        // existing `NotInitialized` checks (which look at `DataKey::Admin`)
+        // continue to work for shared code paths.
+        if let Some(first) = admins.first() {
+            env.storage().instance().set(&DataKey::Admin, &first);
+        }
+
+        Ok(())
+    }
+
+    /// Sets or updates the protocol fee skimmed from future releases.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `fee_bps` - Fee in basis points (1/100th of a percent); must be `<= 10000`
+    /// * `fee_collector` - Address that receives the fee portion of each release
+    ///
+    /// # Returns
+    /// * `Ok(())` - Fee configuration updated
+    /// * `Err(Error::NotInitialized)` - Contract not initialized
+    /// * `Err(Error::Unauthorized)` - Caller is not the admin
+    /// * `Err(Error::FeeTooHigh)` - `fee_bps` exceeds 10000 (100%)
+    ///
+    /// # Notes
+    /// Refunds always bypass the fee entirely; only `release_funds` and
+    /// `claim_funds` skim the configured fee.
+    pub fn set_fee(env: Env, fee_bps: u32, fee_collector: Address) -> Result<(), Error> {
+        if !env.storage().instance().has(&DataKey::Admin) {
+            return Err(Error::NotInitialized);
+        }
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        admin.require_auth();
+
+        if fee_bps > 10000 {
+            return Err(Error::FeeTooHigh);
+        }
+
+        env.storage().instance().set(&DataKey::FeeBps, &fee_bps);
+        env.storage().instance().set(&DataKey::FeeCollector, &fee_collector);
+
+        Ok(())
+    }
+
+    /// Records one admin's approval to release a bounty to a contributor,
+    /// releasing the funds automatically once `threshold` approvals accumulate.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `bounty_id` - The bounty to release
+    /// * `contributor` - The intended recipient of the release
+    /// * `approver` - The admin casting this approval (must authorize)
+    ///
+    /// # Returns
+    /// * `Ok(true)` - This approval reached the threshold; funds were released
+    /// * `Ok(false)` - Approval recorded, but threshold not yet reached
+    /// * `Err(Error::MultisigNotConfigured)` - Contract wasn't set up with `init_multisig`
+    /// * `Err(Error::Unauthorized)` - `approver` is not part of the admin set
+    /// * `Err(Error::AlreadyApproved)` - `approver` already approved this contributor
+    /// * `Err(Error::BountyNotFound)` / `Err(Error::FundsNotLocked)` - Invalid bounty state
+    /// * `Err(Error::DeadlineNotPassed)` - name kept for symmetry; see below
+    ///
+    /// # Approval Scoping
+    /// Approvals are keyed to the `contributor` they were collected for. If a
+    /// different contributor is later proposed, the approval set restarts.
+    ///
+    /// # Expiry
+    /// Once the escrow `deadline` has passed, any stored approvals are
+    /// discarded and this call returns `Err(Error::DeadlineNotPassed)` —
+    /// the depositor's `refund` path is the correct next step.
+    pub fn approve_release(
+        env: Env,
+        bounty_id: u64,
+        contributor: Address,
+        approver: Address,
+    ) -> Result<bool, Error> {
+        approver.require_auth();
+
+        if !env.storage().instance().has(&DataKey::Admins) {
+            return Err(Error::MultisigNotConfigured);
+        }
+
+        let admins: Vec<Address> = env.storage().instance().get(&DataKey::Admins).unwrap();
+        if !admins.contains(&approver) {
+            return Err(Error::Unauthorized);
+        }
+
+        if !env.storage().persistent().has(&DataKey::Escrow(bounty_id)) {
+            return Err(Error::BountyNotFound);
+        }
+        let mut escrow: Escrow = env.storage().persistent().get(&DataKey::Escrow(bounty_id)).unwrap();
+        if escrow.status != EscrowStatus::Locked {
+            return Err(Error::FundsNotLocked);
+        }
+
+        // Expire stale approvals once the deadline has passed; the refund
+        // path is the only way to recover funds from here on.
+        if env.ledger().timestamp() >= escrow.deadline {
+            env.storage().persistent().remove(&DataKey::Approvals(bounty_id));
+            return Err(Error::DeadlineNotPassed);
+        }
+
+        let threshold: u32 = env.storage().instance().get(&DataKey::Threshold).unwrap();
+
+        let mut state: ApprovalState = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Approvals(bounty_id))
+            .unwrap_or(ApprovalState {
+                contributor: contributor.clone(),
+                approvers: Vec::new(&env),
+            });
+
+        // A new intended recipient invalidates any prior approvals.
+        if state.contributor != contributor {
+            state = ApprovalState {
+                contributor: contributor.clone(),
+                approvers: Vec::new(&env),
+            };
+        }
+
+        if state.approvers.contains(&approver) {
+            return Err(Error::AlreadyApproved);
+        }
+        state.approvers.push_back(approver);
+
+        guard_enter(&env, bounty_id)?;
+
+        if state.approvers.len() >= threshold {
+            // Checks-effects-interactions: update escrow state before the
+            // external token transfer so a reentrant call sees funds released.
+            index_transition(&env, bounty_id, &escrow.status, &EscrowStatus::Released);
+            escrow.status = EscrowStatus::Released;
+            escrow.released_amount = escrow.amount;
+            env.storage().persistent().set(&DataKey::Escrow(bounty_id), &escrow);
+            env.storage().persistent().remove(&DataKey::Approvals(bounty_id));
+
+            let token_addr: Address = env.storage().instance().get(&DataKey::Token).unwrap();
+            let client = token::Client::new(&env, &token_addr);
+            client.transfer(&env.current_contract_address(), &contributor, &escrow.amount);
+
+            record_history(&env, bounty_id, EscrowEventKind::Release, escrow.amount, contributor.clone());
+            emit_funds_released(
+                &env,
+                FundsReleased {
+                    bounty_id,
+                    amount: escrow.amount,
+                    recipient: contributor,
+                    timestamp: env.ledger().timestamp(),
+                },
+            );
+
+            guard_exit(&env, bounty_id);
+            Ok(true)
+        } else {
+            env.storage().persistent().set(&DataKey::Approvals(bounty_id), &state);
+            guard_exit(&env, bounty_id);
+            Ok(false)
+        }
+    }
+
     // ========================================================================
     // Core Escrow Functions
     // ========================================================================
@@ -509,24 +1307,31 @@ impl BountyEscrowContract {
             return Err(Error::BountyExists);
         }
 
-        // Get token contract and transfer funds
-        let token_addr: Address = env.storage().instance().get(&DataKey::Token).unwrap();
-        let client = token::Client::new(&env, &token_addr);
-
-        // Transfer funds from depositor to contract
-        client.transfer(&depositor, &env.current_contract_address(), &amount);
+        guard_enter(&env, bounty_id)?;
 
-        // Create escrow record
+        // Checks-effects-interactions: record the escrow before the external
+        // token transfer so a reentrant call sees it already exists.
         let escrow = Escrow {
             depositor: depositor.clone(),
             amount,
             status: EscrowStatus::Locked,
             deadline,
+            hashlock: None,
+            released_amount: 0,
+            approver: None,
+            recovery: None,
+            last_activity: env.ledger().timestamp(),
         };
-
-        // Store in persistent storage with extended TTL
         env.storage().persistent().set(&DataKey::Escrow(bounty_id), &escrow);
-        
+        index_add(&env, &escrow.status, bounty_id);
+
+        // Transfer funds from depositor to contract
+        let token_addr: Address = env.storage().instance().get(&DataKey::Token).unwrap();
+        let client = token::Client::new(&env, &token_addr);
+        client.transfer(&depositor, &env.current_contract_address(), &amount);
+
+        record_history(&env, bounty_id, EscrowEventKind::Deposit, amount, depositor.clone());
+
         // Emit event for off-chain indexing
         emit_funds_locked(
             &env,
@@ -538,32 +1343,416 @@ impl BountyEscrowContract {
             },
         );
 
+        guard_exit(&env, bounty_id);
         Ok(())
     }
 
-    /// Sets or updates metadata for an existing escrow.
+    /// Locks funds in escrow with an attached hashlock, enabling trustless
+    /// release via `claim_funds` without any admin involvement.
     ///
     /// # Arguments
     /// * `env` - The contract environment
-    /// * `bounty_id` - The bounty to attach metadata to
-    /// * `metadata` - Metadata structure containing repo, issue, type, and tags
+    /// * `depositor` - Address depositing the funds (must authorize)
+    /// * `bounty_id` - Unique identifier for this bounty
+    /// * `amount` - Token amount to lock
+    /// * `deadline` - Unix timestamp after which refund is allowed
+    /// * `hashlock` - SHA-256 digest of the secret preimage that unlocks the funds
     ///
     /// # Returns
-    /// * `Ok(())` - Metadata successfully set/updated
-    /// * `Err(Error::BountyNotFound)` - Bounty doesn't exist
-    /// * `Err(Error::MetadataTooLarge)` - Metadata exceeds size limits
-    /// * `Err(Error::Unauthorized)` - Caller is not the depositor
-    ///
-    /// # State Changes
-    /// - Stores/updates metadata in persistent storage
-    /// - Extends storage TTL on access
-    ///
-    /// # Authorization
-    /// - Only the original depositor can set/update metadata
-    /// - This prevents unauthorized metadata modification
-    ///
-    /// # Size Limits
-    /// See `validate_metadata_size()` documentation for detailed limits.
+    /// * `Ok(())` - Funds successfully locked with the hashlock attached
+    /// * `Err(Error::NotInitialized)` / `Err(Error::BountyExists)` - as in `lock_funds`
+    ///
+    /// # Design
+    /// This mirrors `lock_funds` exactly except for storing `hashlock`, so
+    /// the escrow can later be completed either by `claim_funds` (preimage
+    /// holder, no auth) or `refund` (depositor, after `deadline`) — whichever
+    /// condition is met first.
+    pub fn lock_funds_with_hash(
+        env: Env,
+        depositor: Address,
+        bounty_id: u64,
+        amount: i128,
+        deadline: u64,
+        hashlock: BytesN<32>,
+    ) -> Result<(), Error> {
+        depositor.require_auth();
+
+        if !env.storage().instance().has(&DataKey::Admin) {
+            return Err(Error::NotInitialized);
+        }
+
+        if env.storage().persistent().has(&DataKey::Escrow(bounty_id)) {
+            return Err(Error::BountyExists);
+        }
+
+        let token_addr: Address = env.storage().instance().get(&DataKey::Token).unwrap();
+        let client = token::Client::new(&env, &token_addr);
+        client.transfer(&depositor, &env.current_contract_address(), &amount);
+
+        let escrow = Escrow {
+            depositor: depositor.clone(),
+            amount,
+            status: EscrowStatus::Locked,
+            deadline,
+            hashlock: Some(hashlock),
+            released_amount: 0,
+            approver: None,
+            recovery: None,
+            last_activity: env.ledger().timestamp(),
+        };
+        env.storage().persistent().set(&DataKey::Escrow(bounty_id), &escrow);
+        index_add(&env, &escrow.status, bounty_id);
+
+        record_history(&env, bounty_id, EscrowEventKind::Deposit, amount, depositor.clone());
+
+        emit_funds_locked(
+            &env,
+            FundsLocked {
+                bounty_id,
+                amount,
+                depositor,
+                deadline,
+            },
+        );
+
+        Ok(())
+    }
+
+    /// Locks funds for a bounty that is arbitrated by a third-party
+    /// `approver`, enabling the dispute/resolution workflow.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `depositor` - Address locking the funds (must authorize)
+    /// * `bounty_id` - Unique identifier for this bounty
+    /// * `amount` - Token amount to lock
+    /// * `deadline` - Unix timestamp after which refund becomes available
+    /// * `approver` - Third party who arbitrates `release_funds`/disputes
+    ///
+    /// # Returns
+    /// * `Err(Error::NotInitialized)` / `Err(Error::BountyExists)` - as in `lock_funds`
+    ///
+    /// # Design
+    /// Mirrors `lock_funds` except for storing `approver`. With `approver`
+    /// set, `release_funds` must be authorized by `approver` rather than the
+    /// admin, and `open_dispute` becomes available to contest a release
+    /// before it happens.
+    pub fn lock_funds_with_approver(
+        env: Env,
+        depositor: Address,
+        bounty_id: u64,
+        amount: i128,
+        deadline: u64,
+        approver: Address,
+    ) -> Result<(), Error> {
+        depositor.require_auth();
+
+        if !env.storage().instance().has(&DataKey::Admin) {
+            return Err(Error::NotInitialized);
+        }
+
+        if env.storage().persistent().has(&DataKey::Escrow(bounty_id)) {
+            return Err(Error::BountyExists);
+        }
+
+        let token_addr: Address = env.storage().instance().get(&DataKey::Token).unwrap();
+        let client = token::Client::new(&env, &token_addr);
+        client.transfer(&depositor, &env.current_contract_address(), &amount);
+
+        let escrow = Escrow {
+            depositor: depositor.clone(),
+            amount,
+            status: EscrowStatus::Locked,
+            deadline,
+            hashlock: None,
+            released_amount: 0,
+            approver: Some(approver),
+            recovery: None,
+            last_activity: env.ledger().timestamp(),
+        };
+        env.storage().persistent().set(&DataKey::Escrow(bounty_id), &escrow);
+        index_add(&env, &escrow.status, bounty_id);
+
+        record_history(&env, bounty_id, EscrowEventKind::Deposit, amount, depositor.clone());
+
+        emit_funds_locked(
+            &env,
+            FundsLocked {
+                bounty_id,
+                amount,
+                depositor,
+                deadline,
+            },
+        );
+
+        Ok(())
+    }
+
+    /// Contests a pending release, moving the escrow from `Locked` to
+    /// `Disputed` so that only the `approver` can decide its outcome.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `bounty_id` - The bounty to dispute
+    /// * `contributor` - The counterparty the caller claims to be disputing
+    ///   with (the contract does not otherwise track an intended
+    ///   contributor, since `release_funds` names one at release time)
+    ///
+    /// # Returns
+    /// * `Err(Error::BountyNotFound)` - Bounty doesn't exist
+    /// * `Err(Error::MultisigNotConfigured)` - reused here to mean "no approver
+    ///   configured for this bounty"; disputes only make sense when a
+    ///   third-party arbiter exists to resolve them
+    /// * `Err(Error::FundsNotLocked)` - Escrow isn't in `Locked` state
+    ///
+    /// # Authorization
+    /// Either the depositor or `contributor` may open a dispute; whichever
+    /// address is passed as `contributor` must authorize the call (the
+    /// depositor's own authorization when it equals the depositor). Any
+    /// address may name itself as `contributor`.
+    ///
+    /// # Events
+    /// Emits: `DisputeOpened { bounty_id, opened_by, timestamp }`
+    pub fn open_dispute(env: Env, bounty_id: u64, contributor: Address) -> Result<(), Error> {
+        if !env.storage().persistent().has(&DataKey::Escrow(bounty_id)) {
+            return Err(Error::BountyNotFound);
+        }
+
+        let mut escrow: Escrow = env.storage().persistent().get(&DataKey::Escrow(bounty_id)).unwrap();
+
+        if escrow.approver.is_none() {
+            return Err(Error::MultisigNotConfigured);
+        }
+
+        if escrow.status != EscrowStatus::Locked {
+            return Err(Error::FundsNotLocked);
+        }
+
+        let opened_by = if contributor != escrow.depositor {
+            contributor.require_auth();
+            contributor
+        } else {
+            escrow.depositor.require_auth();
+            // The depositor opening a dispute counts as depositor activity.
+            escrow.last_activity = env.ledger().timestamp();
+            escrow.depositor.clone()
+        };
+
+        index_transition(&env, bounty_id, &escrow.status, &EscrowStatus::Disputed);
+        escrow.status = EscrowStatus::Disputed;
+        env.storage().persistent().set(&DataKey::Escrow(bounty_id), &escrow);
+
+        env.events().publish(
+            (symbol_short!("DispOpen"),),
+            (bounty_id, opened_by, env.ledger().timestamp()),
+        );
+
+        Ok(())
+    }
+
+    /// Resolves a disputed escrow in the contributor's favor, releasing the
+    /// full escrowed amount to `recipient`.
+    ///
+    /// # Authorization
+    /// Must be called by the bounty's `approver`.
+    ///
+    /// # Returns
+    /// * `Err(Error::NotDisputed)` - Escrow isn't in `Disputed` state
+    ///
+    /// # Events
+    /// Emits: `FundsReleased` then `DisputeResolved { bounty_id, approved: true, timestamp }`
+    ///
+    /// # Naming
+    /// Named `approve_dispute` rather than `approve_release` because the
+    /// latter already denotes the multisig admin-approval entrypoint.
+    pub fn approve_dispute(env: Env, bounty_id: u64, recipient: Address) -> Result<(), Error> {
+        if !env.storage().persistent().has(&DataKey::Escrow(bounty_id)) {
+            return Err(Error::BountyNotFound);
+        }
+
+        let mut escrow: Escrow = env.storage().persistent().get(&DataKey::Escrow(bounty_id)).unwrap();
+        let approver = escrow.approver.clone().ok_or(Error::MultisigNotConfigured)?;
+        approver.require_auth();
+
+        if escrow.status != EscrowStatus::Disputed {
+            return Err(Error::NotDisputed);
+        }
+
+        guard_enter(&env, bounty_id)?;
+
+        index_transition(&env, bounty_id, &escrow.status, &EscrowStatus::Released);
+        escrow.status = EscrowStatus::Released;
+        escrow.released_amount = escrow.amount;
+        env.storage().persistent().set(&DataKey::Escrow(bounty_id), &escrow);
+
+        let token_addr: Address = env.storage().instance().get(&DataKey::Token).unwrap();
+        let client = token::Client::new(&env, &token_addr);
+        client.transfer(&env.current_contract_address(), &recipient, &escrow.amount);
+
+        record_history(&env, bounty_id, EscrowEventKind::Release, escrow.amount, recipient.clone());
+        emit_funds_released(
+            &env,
+            FundsReleased {
+                bounty_id,
+                amount: escrow.amount,
+                recipient,
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+        env.events().publish(
+            (symbol_short!("DispRslv"),),
+            (bounty_id, true, env.ledger().timestamp()),
+        );
+
+        guard_exit(&env, bounty_id);
+        Ok(())
+    }
+
+    /// Resolves a disputed escrow in the depositor's favor, refunding the
+    /// full escrowed amount back to them.
+    ///
+    /// # Authorization
+    /// Must be called by the bounty's `approver`.
+    ///
+    /// # Returns
+    /// * `Err(Error::NotDisputed)` - Escrow isn't in `Disputed` state
+    ///
+    /// # Events
+    /// Emits: `FundsRefunded` then `DisputeResolved { bounty_id, approved: false, timestamp }`
+    pub fn reject_dispute(env: Env, bounty_id: u64) -> Result<(), Error> {
+        if !env.storage().persistent().has(&DataKey::Escrow(bounty_id)) {
+            return Err(Error::BountyNotFound);
+        }
+
+        let mut escrow: Escrow = env.storage().persistent().get(&DataKey::Escrow(bounty_id)).unwrap();
+        let approver = escrow.approver.clone().ok_or(Error::MultisigNotConfigured)?;
+        approver.require_auth();
+
+        if escrow.status != EscrowStatus::Disputed {
+            return Err(Error::NotDisputed);
+        }
+
+        guard_enter(&env, bounty_id)?;
+
+        index_transition(&env, bounty_id, &escrow.status, &EscrowStatus::Refunded);
+        escrow.status = EscrowStatus::Refunded;
+        env.storage().persistent().set(&DataKey::Escrow(bounty_id), &escrow);
+
+        let token_addr: Address = env.storage().instance().get(&DataKey::Token).unwrap();
+        let client = token::Client::new(&env, &token_addr);
+        client.transfer(&env.current_contract_address(), &escrow.depositor, &escrow.amount);
+
+        record_history(&env, bounty_id, EscrowEventKind::Refund, escrow.amount, escrow.depositor.clone());
+        emit_funds_refunded(
+            &env,
+            FundsRefunded {
+                bounty_id,
+                amount: escrow.amount,
+                refund_to: escrow.depositor.clone(),
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+        env.events().publish(
+            (symbol_short!("DispRslv"),),
+            (bounty_id, false, env.ledger().timestamp()),
+        );
+
+        guard_exit(&env, bounty_id);
+        Ok(())
+    }
+
+    /// Claims a hashlocked escrow by revealing the preimage of its hashlock.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `bounty_id` - The bounty to claim
+    /// * `contributor` - Address to receive the funds
+    /// * `preimage` - Secret whose SHA-256 digest must equal the stored hashlock
+    ///
+    /// # Returns
+    /// * `Ok(())` - Preimage matched; funds transferred and escrow released
+    /// * `Err(Error::BountyNotFound)` - Bounty doesn't exist
+    /// * `Err(Error::FundsNotLocked)` - Funds not in LOCKED state
+    /// * `Err(Error::NoHashlock)` - Escrow wasn't created with a hashlock
+    /// * `Err(Error::Unauthorized)` - Computed hash does not match the stored hashlock
+    ///
+    /// # Authorization
+    /// **Permissionless**: anyone holding the preimage can trigger the claim,
+    /// mirroring the trustless framing already promised for `refund`.
+    pub fn claim_funds(env: Env, bounty_id: u64, contributor: Address, preimage: Bytes) -> Result<(), Error> {
+        if !env.storage().persistent().has(&DataKey::Escrow(bounty_id)) {
+            return Err(Error::BountyNotFound);
+        }
+
+        let mut escrow: Escrow = env.storage().persistent().get(&DataKey::Escrow(bounty_id)).unwrap();
+        if escrow.status != EscrowStatus::Locked {
+            return Err(Error::FundsNotLocked);
+        }
+
+        let hashlock = escrow.hashlock.clone().ok_or(Error::NoHashlock)?;
+        let computed: BytesN<32> = env.crypto().sha256(&preimage).into();
+        if computed != hashlock {
+            return Err(Error::Unauthorized);
+        }
+
+        guard_enter(&env, bounty_id)?;
+
+        let (net_amount, fee) = split_fee(&env, escrow.amount);
+
+        // Checks-effects-interactions: flip status before the external transfer.
+        index_transition(&env, bounty_id, &escrow.status, &EscrowStatus::Released);
+        escrow.status = EscrowStatus::Released;
+        env.storage().persistent().set(&DataKey::Escrow(bounty_id), &escrow);
+
+        let token_addr: Address = env.storage().instance().get(&DataKey::Token).unwrap();
+        let client = token::Client::new(&env, &token_addr);
+        if fee > 0 {
+            let fee_collector: Address = env.storage().instance().get(&DataKey::FeeCollector).unwrap();
+            client.transfer(&env.current_contract_address(), &fee_collector, &fee);
+        }
+        client.transfer(&env.current_contract_address(), &contributor, &net_amount);
+
+        record_history(&env, bounty_id, EscrowEventKind::Release, net_amount, contributor.clone());
+        emit_funds_released(
+            &env,
+            FundsReleased {
+                bounty_id,
+                amount: net_amount,
+                recipient: contributor,
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+        if fee > 0 {
+            env.events().publish((symbol_short!("FeeTaken"),), (bounty_id, net_amount, fee));
+        }
+
+        guard_exit(&env, bounty_id);
+        Ok(())
+    }
+
+    /// Sets or updates metadata for an existing escrow.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `bounty_id` - The bounty to attach metadata to
+    /// * `metadata` - Metadata structure containing repo, issue, type, and tags
+    ///
+    /// # Returns
+    /// * `Ok(())` - Metadata successfully set/updated
+    /// * `Err(Error::BountyNotFound)` - Bounty doesn't exist
+    /// * `Err(Error::MetadataTooLarge)` - Metadata exceeds size limits
+    /// * `Err(Error::Unauthorized)` - Caller is not the depositor
+    ///
+    /// # State Changes
+    /// - Stores/updates metadata in persistent storage
+    /// - Extends storage TTL on access
+    ///
+    /// # Authorization
+    /// - Only the original depositor can set/update metadata
+    /// - This prevents unauthorized metadata modification
+    ///
+    /// # Size Limits
+    /// See `validate_metadata_size()` documentation for detailed limits.
     ///
     /// # Events
     /// Emits: `FundsLocked` event with additional metadata field
@@ -630,6 +1819,8 @@ impl BountyEscrowContract {
     /// * `Err(Error::Unauthorized)` - Caller is not the admin
     /// * `Err(Error::BountyNotFound)` - Bounty doesn't exist
     /// * `Err(Error::FundsNotLocked)` - Funds not in LOCKED state
+    /// * `Err(Error::MultisigRequired)` - Contract was initialized via
+    ///   `init_multisig`; use `approve_release` instead
     ///
     /// # State Changes
     /// - Transfers tokens from contract to contributor
@@ -639,13 +1830,20 @@ impl BountyEscrowContract {
     /// # Authorization
     /// - **CRITICAL**: Only admin can call this function
     /// - Admin address must match initialization value
+    /// - Rejected outright when the contract was initialized via
+    ///   `init_multisig`; that single-admin slot exists only so unrelated
+    ///   `NotInitialized` checks keep working, not as a release bypass
+    /// - When the escrow has an `approver`, that approver authorizes the
+    ///   release instead of the admin
     ///
     /// # Security Considerations
     /// - This is the most security-critical function
     /// - Admin should verify task completion off-chain before calling
     /// - Once released, funds cannot be retrieved
     /// - Recipient address should be verified carefully
-    /// - Consider implementing multi-sig for admin
+    /// - Contracts initialized via `init_multisig` must release through
+    ///   `approve_release`'s M-of-N quorum; this function refuses to act as
+    ///   a single-signer bypass for them
     ///
     /// # Events
     /// Emits: `FundsReleased { bounty_id, amount, recipient, timestamp }`
@@ -675,45 +1873,1049 @@ impl BountyEscrowContract {
             return Err(Error::NotInitialized);
         }
 
-        // Verify admin authorization
-        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
-        admin.require_auth();
-
-        // Verify bounty exists
+        // Verify bounty exists
+        if !env.storage().persistent().has(&DataKey::Escrow(bounty_id)) {
+            return Err(Error::BountyNotFound);
+        }
+
+        // Get and verify escrow state
+        let mut escrow: Escrow = env.storage().persistent().get(&DataKey::Escrow(bounty_id)).unwrap();
+
+        // When an approver is configured, they alone authorize the release
+        // instead of the admin (see `open_dispute`/`approve_dispute`).
+        if let Some(approver) = escrow.approver.clone() {
+            approver.require_auth();
+        } else if env.storage().instance().has(&DataKey::Admins) {
+            // The contract was initialized via `init_multisig`. The single
+            // admin slot is only populated so unrelated `NotInitialized`
+            // checks keep working - it must not let one signer bypass the
+            // M-of-N quorum. Callers must go through `approve_release`.
+            return Err(Error::MultisigRequired);
+        } else {
+            let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+            admin.require_auth();
+        }
+
+        if escrow.status != EscrowStatus::Locked {
+            return Err(Error::FundsNotLocked);
+        }
+
+        guard_enter(&env, bounty_id)?;
+
+        // Unwind any active stake first so the balance is on hand to release.
+        unstake_if_staked(&env, bounty_id)?;
+
+        // Checks-effects-interactions: update escrow state before the
+        // external token transfers so a reentrant call sees funds released.
+        let (net_amount, fee) = split_fee(&env, escrow.amount);
+        index_transition(&env, bounty_id, &escrow.status, &EscrowStatus::Released);
+        escrow.status = EscrowStatus::Released;
+        escrow.released_amount = escrow.amount;
+        env.storage().persistent().set(&DataKey::Escrow(bounty_id), &escrow);
+
+        // Transfer funds to contributor, skimming the protocol fee if configured
+        let token_addr: Address = env.storage().instance().get(&DataKey::Token).unwrap();
+        let client = token::Client::new(&env, &token_addr);
+        if fee > 0 {
+            let fee_collector: Address = env.storage().instance().get(&DataKey::FeeCollector).unwrap();
+            client.transfer(&env.current_contract_address(), &fee_collector, &fee);
+        }
+        client.transfer(&env.current_contract_address(), &contributor, &net_amount);
+
+        record_history(&env, bounty_id, EscrowEventKind::Release, net_amount, contributor.clone());
+
+        // Emit release event
+        emit_funds_released(
+            &env,
+            FundsReleased {
+                bounty_id,
+                amount: net_amount,
+                recipient: contributor.clone(),
+                timestamp: env.ledger().timestamp()
+            },
+        );
+        if fee > 0 {
+            env.events().publish((symbol_short!("FeeTaken"),), (bounty_id, net_amount, fee));
+        }
+
+        guard_exit(&env, bounty_id);
+        Ok(())
+    }
+
+    /// Releases part of an escrowed bounty to a contributor, allowing a
+    /// single bounty to be split across multiple payments over time.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `bounty_id` - The bounty to release from
+    /// * `contributor` - Address to receive this partial payment
+    /// * `amount` - Amount to release now (must not exceed the unreleased remainder)
+    ///
+    /// # Returns
+    /// * `Ok(())` - Partial payment transferred and escrow state updated
+    /// * `Err(Error::NotInitialized)` / `Err(Error::Unauthorized)` - admin checks, as `release_funds`
+    /// * `Err(Error::BountyNotFound)` - Bounty doesn't exist
+    /// * `Err(Error::FundsNotLocked)` - Funds already fully `Released` or `Refunded`
+    /// * `Err(Error::MultisigRequired)` - Contract was initialized via
+    ///   `init_multisig`; this bounty has no per-escrow `approver` override,
+    ///   so `approve_release` must be used instead of a single signer
+    ///
+    /// # State Changes
+    /// - Transfers `amount` tokens from contract to contributor
+    /// - Increments `released_amount` by `amount`
+    /// - Sets status to `Partial` if some balance remains, or `Released` once
+    ///   `released_amount` reaches `amount` (the escrow's total)
+    ///
+    /// # Events
+    /// Emits: `FundsReleased { bounty_id, amount, recipient, timestamp }` per call,
+    /// with `amount` being this call's payment; the running total released
+    /// so far can be read from `get_escrow_info(bounty_id).released_amount`.
+    pub fn release_partial(
+        env: Env,
+        bounty_id: u64,
+        contributor: Address,
+        amount: i128,
+    ) -> Result<(), Error> {
+        if !env.storage().instance().has(&DataKey::Admin) {
+            return Err(Error::NotInitialized);
+        }
+
+        if !env.storage().persistent().has(&DataKey::Escrow(bounty_id)) {
+            return Err(Error::BountyNotFound);
+        }
+
+        let mut escrow: Escrow = env.storage().persistent().get(&DataKey::Escrow(bounty_id)).unwrap();
+
+        if let Some(approver) = escrow.approver.clone() {
+            approver.require_auth();
+        } else if env.storage().instance().has(&DataKey::Admins) {
+            return Err(Error::MultisigRequired);
+        } else {
+            let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+            admin.require_auth();
+        }
+
+        if escrow.status != EscrowStatus::Locked && escrow.status != EscrowStatus::Partial {
+            return Err(Error::FundsNotLocked);
+        }
+
+        let remaining = escrow.amount - escrow.released_amount;
+        if amount <= 0 || amount > remaining {
+            return Err(Error::Unauthorized);
+        }
+
+        guard_enter(&env, bounty_id)?;
+
+        // Unwind any active stake first so the balance is on hand to release.
+        unstake_if_staked(&env, bounty_id)?;
+
+        let token_addr: Address = env.storage().instance().get(&DataKey::Token).unwrap();
+        let client = token::Client::new(&env, &token_addr);
+        client.transfer(&env.current_contract_address(), &contributor, &amount);
+
+        escrow.released_amount += amount;
+        let new_status = if escrow.released_amount == escrow.amount {
+            EscrowStatus::Released
+        } else {
+            EscrowStatus::Partial
+        };
+        index_transition(&env, bounty_id, &escrow.status, &new_status);
+        escrow.status = new_status;
+        env.storage().persistent().set(&DataKey::Escrow(bounty_id), &escrow);
+
+        record_history(&env, bounty_id, EscrowEventKind::Release, amount, contributor.clone());
+        emit_funds_released(
+            &env,
+            FundsReleased {
+                bounty_id,
+                amount,
+                recipient: contributor,
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+
+        guard_exit(&env, bounty_id);
+        Ok(())
+    }
+
+    /// Sets up a milestone-based release schedule for a bounty, as an
+    /// alternative to the free-form `release_partial`.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `bounty_id` - The bounty to schedule
+    /// * `milestones` - Ordered list of milestones; amounts must sum to the
+    ///   escrow's total `amount`
+    ///
+    /// # Returns
+    /// * `Err(Error::NotInitialized)` / `Err(Error::Unauthorized)` - admin checks
+    /// * `Err(Error::BountyNotFound)` - Bounty doesn't exist
+    /// * `Err(Error::FundsNotLocked)` - Escrow isn't `Locked`
+    /// * `Err(Error::Unauthorized)` - Milestone amounts don't sum to `escrow.amount`
+    ///
+    /// # Design
+    /// Replaces any existing schedule for this bounty. Milestones start
+    /// `released: false`; `release_milestone` pays them out one at a time.
+    pub fn create_milestones(
+        env: Env,
+        bounty_id: u64,
+        milestones: Vec<Milestone>,
+    ) -> Result<(), Error> {
+        if !env.storage().instance().has(&DataKey::Admin) {
+            return Err(Error::NotInitialized);
+        }
+
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        admin.require_auth();
+
+        if !env.storage().persistent().has(&DataKey::Escrow(bounty_id)) {
+            return Err(Error::BountyNotFound);
+        }
+
+        let escrow: Escrow = env.storage().persistent().get(&DataKey::Escrow(bounty_id)).unwrap();
+        if escrow.status != EscrowStatus::Locked {
+            return Err(Error::FundsNotLocked);
+        }
+
+        let mut total: i128 = 0;
+        for milestone in milestones.iter() {
+            total += milestone.amount;
+        }
+        if total != escrow.amount {
+            return Err(Error::Unauthorized);
+        }
+
+        env.storage().persistent().set(&DataKey::Milestones(bounty_id), &milestones);
+        Ok(())
+    }
+
+    /// Releases a single milestone's payment to `contributor`.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `bounty_id` - The bounty to release from
+    /// * `milestone_index` - Index into the schedule created by `create_milestones`
+    /// * `contributor` - Address to receive this milestone's payment
+    ///
+    /// # Returns
+    /// * `Err(Error::NotInitialized)` / `Err(Error::Unauthorized)` - admin checks
+    /// * `Err(Error::BountyNotFound)` - No milestone schedule for this bounty
+    /// * `Err(Error::FundsNotLocked)` - `milestone_index` out of range or already released
+    /// * `Err(Error::MultisigRequired)` - Contract was initialized via
+    ///   `init_multisig`; this bounty has no per-escrow `approver` override,
+    ///   so `approve_release` must be used instead of a single signer
+    ///
+    /// # State Changes
+    /// - Transfers the milestone's `amount` to `contributor`
+    /// - Marks the milestone `released` and updates `escrow.released_amount`
+    /// - Transitions the escrow to `Released` once every milestone is paid,
+    ///   otherwise leaves it (or moves it to) `Partial`
+    pub fn release_milestone(
+        env: Env,
+        bounty_id: u64,
+        milestone_index: u32,
+        contributor: Address,
+    ) -> Result<(), Error> {
+        if !env.storage().instance().has(&DataKey::Admin) {
+            return Err(Error::NotInitialized);
+        }
+
+        if !env.storage().persistent().has(&DataKey::Milestones(bounty_id)) {
+            return Err(Error::BountyNotFound);
+        }
+
+        let mut milestones: Vec<Milestone> =
+            env.storage().persistent().get(&DataKey::Milestones(bounty_id)).unwrap();
+        if milestone_index >= milestones.len() {
+            return Err(Error::FundsNotLocked);
+        }
+
+        let mut milestone = milestones.get(milestone_index).unwrap();
+        if milestone.released {
+            return Err(Error::FundsNotLocked);
+        }
+
+        let mut escrow: Escrow = env.storage().persistent().get(&DataKey::Escrow(bounty_id)).unwrap();
+
+        if let Some(approver) = escrow.approver.clone() {
+            approver.require_auth();
+        } else if env.storage().instance().has(&DataKey::Admins) {
+            return Err(Error::MultisigRequired);
+        } else {
+            let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+            admin.require_auth();
+        }
+
+        guard_enter(&env, bounty_id)?;
+
+        milestone.released = true;
+        milestones.set(milestone_index, milestone.clone());
+        env.storage().persistent().set(&DataKey::Milestones(bounty_id), &milestones);
+
+        escrow.released_amount += milestone.amount;
+        let new_status = if escrow.released_amount == escrow.amount {
+            EscrowStatus::Released
+        } else {
+            EscrowStatus::Partial
+        };
+        index_transition(&env, bounty_id, &escrow.status, &new_status);
+        escrow.status = new_status;
+        env.storage().persistent().set(&DataKey::Escrow(bounty_id), &escrow);
+
+        let token_addr: Address = env.storage().instance().get(&DataKey::Token).unwrap();
+        let client = token::Client::new(&env, &token_addr);
+        client.transfer(&env.current_contract_address(), &contributor, &milestone.amount);
+
+        record_history(&env, bounty_id, EscrowEventKind::Release, milestone.amount, contributor.clone());
+        emit_funds_released(
+            &env,
+            FundsReleased {
+                bounty_id,
+                amount: milestone.amount,
+                recipient: contributor,
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+
+        guard_exit(&env, bounty_id);
+        Ok(())
+    }
+
+    /// Returns `(total, released, remaining)` for a bounty's milestone
+    /// release schedule.
+    ///
+    /// Not to be confused with `get_release_schedule`, which queries the
+    /// separate admin-managed `ReleaseSchedule` mechanism created via
+    /// `create_release_schedule`/`create_vesting_schedule`.
+    ///
+    /// # Returns
+    /// * `Err(Error::BountyNotFound)` - No milestone schedule for this bounty
+    pub fn get_milestone_schedule(env: Env, bounty_id: u64) -> Result<(i128, i128, i128), Error> {
+        if !env.storage().persistent().has(&DataKey::Milestones(bounty_id)) {
+            return Err(Error::BountyNotFound);
+        }
+
+        let milestones: Vec<Milestone> =
+            env.storage().persistent().get(&DataKey::Milestones(bounty_id)).unwrap();
+        let escrow: Escrow = env.storage().persistent().get(&DataKey::Escrow(bounty_id)).unwrap();
+
+        let mut total: i128 = 0;
+        for milestone in milestones.iter() {
+            total += milestone.amount;
+        }
+
+        Ok((total, escrow.released_amount, total - escrow.released_amount))
+    }
+
+    /// Sets up a fixed-timestamp release schedule: the full `amount` becomes
+    /// claimable by `recipient` once `release_timestamp` passes, via
+    /// `release_schedule_automatic` (permissionless) or
+    /// `release_schedule_manual` (admin, can force it early).
+    ///
+    /// A bounty may have several schedules outstanding at once, each
+    /// identified by the returned `schedule_id` (1-based, unique per bounty).
+    ///
+    /// # Returns
+    /// * `Ok(schedule_id)` - The new schedule's identifier
+    /// * `Err(Error::NotInitialized)` / `Err(Error::Unauthorized)` - admin checks
+    /// * `Err(Error::BountyNotFound)` - Bounty doesn't exist
+    /// * `Err(Error::FundsNotLocked)` - Escrow isn't `Locked` or `Partial`
+    /// * `Err(Error::Unauthorized)` - `amount` isn't positive
+    pub fn create_release_schedule(
+        env: Env,
+        bounty_id: u64,
+        amount: i128,
+        release_timestamp: u64,
+        recipient: Address,
+    ) -> Result<u32, Error> {
+        if !env.storage().instance().has(&DataKey::Admin) {
+            return Err(Error::NotInitialized);
+        }
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        admin.require_auth();
+
+        if !env.storage().persistent().has(&DataKey::Escrow(bounty_id)) {
+            return Err(Error::BountyNotFound);
+        }
+        let escrow: Escrow = env.storage().persistent().get(&DataKey::Escrow(bounty_id)).unwrap();
+        if escrow.status != EscrowStatus::Locked && escrow.status != EscrowStatus::Partial {
+            return Err(Error::FundsNotLocked);
+        }
+
+        if amount <= 0 {
+            return Err(Error::Unauthorized);
+        }
+
+        let mut schedules: Vec<ReleaseSchedule> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Schedules(bounty_id))
+            .unwrap_or(Vec::new(&env));
+        let schedule_id = schedules.len() + 1;
+        schedules.push_back(ReleaseSchedule {
+            schedule_id,
+            amount,
+            release_timestamp,
+            recipient,
+            released: false,
+            released_at: None,
+            released_by: None,
+            is_vesting: false,
+            start_ts: 0,
+            end_ts: 0,
+            cliff_ts: 0,
+            step_seconds: 0,
+            released_amount: 0,
+            terminated: false,
+        });
+        env.storage().persistent().set(&DataKey::Schedules(bounty_id), &schedules);
+
+        Ok(schedule_id)
+    }
+
+    /// Sets up a linear vesting schedule, modeled on step-based vesting:
+    /// nothing is claimable before `cliff_ts`, then the claimable amount
+    /// grows in discrete `step_seconds` buckets between `start_ts` and
+    /// `end_ts`, reaching `total_amount` at `end_ts`. Unlike
+    /// `create_release_schedule`'s all-or-nothing payout, vested amounts are
+    /// drawn down incrementally via `claim_vested`.
+    ///
+    /// # Returns
+    /// * `Ok(schedule_id)` - The new schedule's identifier
+    /// * `Err(Error::NotInitialized)` / `Err(Error::Unauthorized)` - admin checks
+    /// * `Err(Error::BountyNotFound)` - Bounty doesn't exist
+    /// * `Err(Error::FundsNotLocked)` - Escrow isn't `Locked` or `Partial`
+    /// * `Err(Error::Unauthorized)` - `total_amount` isn't positive, `end_ts`
+    ///   isn't after `start_ts`, `step_seconds` is zero, or `cliff_ts` falls
+    ///   outside `[start_ts, end_ts]`
+    pub fn create_vesting_schedule(
+        env: Env,
+        bounty_id: u64,
+        total_amount: i128,
+        start_ts: u64,
+        end_ts: u64,
+        cliff_ts: u64,
+        step_seconds: u64,
+        recipient: Address,
+    ) -> Result<u32, Error> {
+        if !env.storage().instance().has(&DataKey::Admin) {
+            return Err(Error::NotInitialized);
+        }
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        admin.require_auth();
+
+        if !env.storage().persistent().has(&DataKey::Escrow(bounty_id)) {
+            return Err(Error::BountyNotFound);
+        }
+        let escrow: Escrow = env.storage().persistent().get(&DataKey::Escrow(bounty_id)).unwrap();
+        if escrow.status != EscrowStatus::Locked && escrow.status != EscrowStatus::Partial {
+            return Err(Error::FundsNotLocked);
+        }
+
+        if total_amount <= 0
+            || end_ts <= start_ts
+            || step_seconds == 0
+            || cliff_ts < start_ts
+            || cliff_ts > end_ts
+        {
+            return Err(Error::Unauthorized);
+        }
+
+        let mut schedules: Vec<ReleaseSchedule> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Schedules(bounty_id))
+            .unwrap_or(Vec::new(&env));
+        let schedule_id = schedules.len() + 1;
+        schedules.push_back(ReleaseSchedule {
+            schedule_id,
+            amount: total_amount,
+            release_timestamp: end_ts,
+            recipient,
+            released: false,
+            released_at: None,
+            released_by: None,
+            is_vesting: true,
+            start_ts,
+            end_ts,
+            cliff_ts,
+            step_seconds,
+            released_amount: 0,
+            terminated: false,
+        });
+        env.storage().persistent().set(&DataKey::Schedules(bounty_id), &schedules);
+
+        Ok(schedule_id)
+    }
+
+    /// Pays out a fixed-timestamp schedule once its `release_timestamp` has
+    /// passed.
+    ///
+    /// # Authorization
+    /// **Permissionless**: anyone can trigger the release once it's due,
+    /// mirroring `refund`/`recover`. `released_by` is recorded as the
+    /// contract's own address, since no particular caller is required.
+    ///
+    /// # Returns
+    /// * `Err(Error::BountyNotFound)` - No schedule exists for this bounty
+    /// * `Err(Error::ScheduleNotFound)` - `schedule_id` doesn't exist, or
+    ///   names a vesting schedule (use `claim_vested` for those)
+    /// * `Err(Error::ScheduleAlreadyReleased)` - Already paid out
+    /// * `Err(Error::ScheduleNotDue)` - `release_timestamp` hasn't passed
+    /// * `Err(Error::FundsNotLocked)` - Escrow isn't `Locked` or `Partial`
+    pub fn release_schedule_automatic(env: Env, bounty_id: u64, schedule_id: u32) -> Result<(), Error> {
+        let empty_approvers = Vec::new(&env);
+        pay_out_schedule(env, bounty_id, schedule_id, None, ReleaseType::Automatic, empty_approvers)
+    }
+
+    /// Forces an early payout of a fixed-timestamp schedule, bypassing its
+    /// `release_timestamp`.
+    ///
+    /// # Authorization
+    /// Must be called by the contract admin. Once `set_schedule_approvers`
+    /// has registered a distinct approver set, this single-admin path is
+    /// rejected — `approve_schedule_release`'s quorum is the only way to
+    /// force an early release, so the approver role stays meaningfully
+    /// separate from the admin.
+    ///
+    /// # Returns
+    /// * `Err(Error::MultisigRequired)` - `set_schedule_approvers` has
+    ///   configured an approver set; use `approve_schedule_release` instead
+    /// * Otherwise, same as `release_schedule_automatic`, except
+    ///   `Err(Error::ScheduleNotDue)` is never returned since the timestamp
+    ///   check is skipped.
+    pub fn release_schedule_manual(env: Env, bounty_id: u64, schedule_id: u32) -> Result<(), Error> {
+        if !env.storage().instance().has(&DataKey::Admin) {
+            return Err(Error::NotInitialized);
+        }
+        if env.storage().instance().has(&DataKey::ScheduleApprovers) {
+            return Err(Error::MultisigRequired);
+        }
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        admin.require_auth();
+
+        let empty_approvers = Vec::new(&env);
+        pay_out_schedule(env, bounty_id, schedule_id, Some(admin), ReleaseType::Manual, empty_approvers)
+    }
+
+    /// Registers the set of addresses authorized to sign off on early
+    /// `ReleaseSchedule` releases via `approve_schedule_release`, separate
+    /// from the fund-locking `Admin`. This lets who funds a bounty differ
+    /// from who attests that the underlying work is done.
+    ///
+    /// # Arguments
+    /// * `approvers` - The addresses eligible to cast an approval
+    /// * `required_approvals` - Distinct approvals needed before a schedule
+    ///   release executes; must be in `1..=approvers.len()`
+    ///
+    /// # Authorization
+    /// Must be called by the contract admin.
+    ///
+    /// # Returns
+    /// * `Err(Error::Unauthorized)` - `required_approvals` is zero or exceeds `approvers.len()`
+    pub fn set_schedule_approvers(
+        env: Env,
+        approvers: Vec<Address>,
+        required_approvals: u32,
+    ) -> Result<(), Error> {
+        if !env.storage().instance().has(&DataKey::Admin) {
+            return Err(Error::NotInitialized);
+        }
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        admin.require_auth();
+
+        if required_approvals == 0 || required_approvals > approvers.len() {
+            return Err(Error::Unauthorized);
+        }
+
+        env.storage().instance().set(&DataKey::ScheduleApprovers, &approvers);
+        env.storage().instance().set(&DataKey::ScheduleApprovalThreshold, &required_approvals);
+        Ok(())
+    }
+
+    /// Casts one approver's signature toward releasing a fixed-timestamp
+    /// schedule early, executing the payout once `required_approvals`
+    /// distinct signatures have accumulated.
+    ///
+    /// # Arguments
+    /// * `approver` - Must be part of the set registered via
+    ///   `set_schedule_approvers`, and must authorize this call
+    ///
+    /// # Returns
+    /// * `Ok(true)` - This approval reached the threshold; the schedule was released
+    /// * `Ok(false)` - Approval recorded, but the threshold isn't reached yet
+    /// * `Err(Error::ScheduleApproversNotConfigured)` - `set_schedule_approvers` was never called
+    /// * `Err(Error::Unauthorized)` - `approver` is not part of the registered set
+    /// * `Err(Error::BountyNotFound)` - No schedule exists for this bounty
+    /// * `Err(Error::ScheduleNotFound)` - `schedule_id` doesn't exist, or
+    ///   names a vesting schedule (use `claim_vested` for those)
+    /// * `Err(Error::ScheduleAlreadyReleased)` - Already paid out or terminated
+    /// * `Err(Error::AlreadyApproved)` - `approver` already signed for this schedule
+    ///
+    /// # State
+    /// Accumulated approvals are scoped to the `(bounty_id, schedule_id)` pair
+    /// and cleared once the threshold is reached and the release executes.
+    pub fn approve_schedule_release(
+        env: Env,
+        bounty_id: u64,
+        schedule_id: u32,
+        approver: Address,
+    ) -> Result<bool, Error> {
+        approver.require_auth();
+
+        if !env.storage().instance().has(&DataKey::ScheduleApprovers) {
+            return Err(Error::ScheduleApproversNotConfigured);
+        }
+        let registered: Vec<Address> = env.storage().instance().get(&DataKey::ScheduleApprovers).unwrap();
+        if !registered.contains(&approver) {
+            return Err(Error::Unauthorized);
+        }
+
+        if !env.storage().persistent().has(&DataKey::Schedules(bounty_id)) {
+            return Err(Error::BountyNotFound);
+        }
+        let schedules: Vec<ReleaseSchedule> =
+            env.storage().persistent().get(&DataKey::Schedules(bounty_id)).unwrap();
+        let index = find_schedule_index(&schedules, schedule_id).ok_or(Error::ScheduleNotFound)?;
+        let schedule = schedules.get(index).unwrap();
+        if schedule.is_vesting {
+            return Err(Error::ScheduleNotFound);
+        }
+        if schedule.released || schedule.terminated {
+            return Err(Error::ScheduleAlreadyReleased);
+        }
+
+        let approval_key = DataKey::ScheduleApprovals(bounty_id, schedule_id);
+        let mut state: ScheduleApprovalState = env
+            .storage()
+            .persistent()
+            .get(&approval_key)
+            .unwrap_or(ScheduleApprovalState { approvers: Vec::new(&env) });
+
+        if state.approvers.contains(&approver) {
+            return Err(Error::AlreadyApproved);
+        }
+        state.approvers.push_back(approver.clone());
+
+        let threshold: u32 = env.storage().instance().get(&DataKey::ScheduleApprovalThreshold).unwrap();
+        if state.approvers.len() >= threshold {
+            env.storage().persistent().remove(&approval_key);
+            pay_out_schedule(env, bounty_id, schedule_id, Some(approver), ReleaseType::Manual, state.approvers)?;
+            Ok(true)
+        } else {
+            env.storage().persistent().set(&approval_key, &state);
+            Ok(false)
+        }
+    }
+
+    /// Claims the currently-vested amount of a `create_vesting_schedule`
+    /// schedule, transferring the delta over what's already been claimed.
+    ///
+    /// `vested = total_amount * floor((now - start_ts)/step_seconds) *
+    /// step_seconds / (end_ts - start_ts)`, clamped to `total_amount`, with
+    /// nothing vested before `cliff_ts`. Records a `ReleaseType::Vested`
+    /// entry in `get_release_history`.
+    ///
+    /// # Authorization
+    /// Must be called by the schedule's `recipient`.
+    ///
+    /// # Returns
+    /// * `Ok(amount)` - The newly-vested amount transferred this call
+    /// * `Err(Error::BountyNotFound)` - No schedule exists for this bounty
+    /// * `Err(Error::ScheduleNotFound)` - `schedule_id` doesn't exist, or
+    ///   names a fixed-timestamp schedule (use `release_schedule_automatic`/
+    ///   `release_schedule_manual` for those)
+    /// * `Err(Error::ScheduleAlreadyReleased)` - `total_amount` fully claimed
+    /// * `Err(Error::ScheduleNotDue)` - Before `cliff_ts`, or nothing new has
+    ///   vested since the last claim
+    /// * `Err(Error::FundsNotLocked)` - Escrow isn't `Locked` or `Partial`
+    pub fn claim_vested(env: Env, bounty_id: u64, schedule_id: u32) -> Result<i128, Error> {
+        if !env.storage().persistent().has(&DataKey::Schedules(bounty_id)) {
+            return Err(Error::BountyNotFound);
+        }
+        let mut schedules: Vec<ReleaseSchedule> =
+            env.storage().persistent().get(&DataKey::Schedules(bounty_id)).unwrap();
+        let index = find_schedule_index(&schedules, schedule_id).ok_or(Error::ScheduleNotFound)?;
+        let mut schedule = schedules.get(index).unwrap();
+
+        if !schedule.is_vesting {
+            return Err(Error::ScheduleNotFound);
+        }
+        if schedule.released || schedule.terminated {
+            return Err(Error::ScheduleAlreadyReleased);
+        }
+
+        schedule.recipient.require_auth();
+
+        let now = env.ledger().timestamp();
+        let vested = vested_amount(now, &schedule);
+        let delta = vested - schedule.released_amount;
+        if delta <= 0 {
+            return Err(Error::ScheduleNotDue);
+        }
+
+        let mut escrow: Escrow = env.storage().persistent().get(&DataKey::Escrow(bounty_id)).unwrap();
+        if escrow.status != EscrowStatus::Locked && escrow.status != EscrowStatus::Partial {
+            return Err(Error::FundsNotLocked);
+        }
+
+        guard_enter(&env, bounty_id)?;
+
+        // Unwind any active stake first so the balance is on hand to release.
+        unstake_if_staked(&env, bounty_id)?;
+
+        schedule.released_amount = vested;
+        schedule.released = vested >= schedule.amount;
+        schedule.released_at = Some(now);
+        schedule.released_by = Some(schedule.recipient.clone());
+        schedules.set(index, schedule.clone());
+        env.storage().persistent().set(&DataKey::Schedules(bounty_id), &schedules);
+
+        escrow.released_amount += delta;
+        let new_status = if escrow.released_amount == escrow.amount {
+            EscrowStatus::Released
+        } else {
+            EscrowStatus::Partial
+        };
+        index_transition(&env, bounty_id, &escrow.status, &new_status);
+        escrow.status = new_status;
+        env.storage().persistent().set(&DataKey::Escrow(bounty_id), &escrow);
+
+        let token_addr: Address = env.storage().instance().get(&DataKey::Token).unwrap();
+        let client = token::Client::new(&env, &token_addr);
+        client.transfer(&env.current_contract_address(), &schedule.recipient, &delta);
+
+        record_history(&env, bounty_id, EscrowEventKind::Release, delta, schedule.recipient.clone());
+        record_schedule_release(&env, bounty_id, schedule_id, delta, schedule.recipient.clone(), ReleaseType::Vested, Vec::new(&env));
+        emit_funds_released(
+            &env,
+            FundsReleased {
+                bounty_id,
+                amount: delta,
+                recipient: schedule.recipient,
+                timestamp: now,
+            },
+        );
+
+        guard_exit(&env, bounty_id);
+        Ok(delta)
+    }
+
+    /// Cancels an unreleased (or partially-vested) `ReleaseSchedule`,
+    /// refunding whatever is still locked back to the escrow's `depositor`.
+    ///
+    /// For a vesting schedule, any amount already vested but not yet claimed
+    /// is paid out to the `recipient` first (recorded as its own `Vested`
+    /// entry in `get_release_history`), and only the remainder is refunded.
+    /// Once terminated, the schedule is excluded from `get_pending_schedules`
+    /// and rejected by `release_schedule_automatic`/`release_schedule_manual`/
+    /// `claim_vested`.
+    ///
+    /// # Arguments
+    /// * `caller` - Must be the contract admin or the escrow's `depositor`;
+    ///   provides their own authorization, mirroring `open_dispute`
+    ///
+    /// # Returns
+    /// * `Ok(refunded)` - The amount transferred back to the depositor
+    /// * `Err(Error::BountyNotFound)` - No schedule exists for this bounty
+    /// * `Err(Error::ScheduleNotFound)` - `schedule_id` doesn't exist
+    /// * `Err(Error::ScheduleAlreadyReleased)` - Already fully paid out or
+    ///   already terminated
+    /// * `Err(Error::Unauthorized)` - `caller` is neither the admin nor the depositor
+    /// * `Err(Error::FundsNotLocked)` - Escrow isn't `Locked` or `Partial`
+    pub fn terminate_schedule(
+        env: Env,
+        bounty_id: u64,
+        schedule_id: u32,
+        caller: Address,
+    ) -> Result<i128, Error> {
+        if !env.storage().instance().has(&DataKey::Admin) {
+            return Err(Error::NotInitialized);
+        }
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+
+        if !env.storage().persistent().has(&DataKey::Schedules(bounty_id)) {
+            return Err(Error::BountyNotFound);
+        }
+        let mut schedules: Vec<ReleaseSchedule> =
+            env.storage().persistent().get(&DataKey::Schedules(bounty_id)).unwrap();
+        let index = find_schedule_index(&schedules, schedule_id).ok_or(Error::ScheduleNotFound)?;
+        let mut schedule = schedules.get(index).unwrap();
+
+        if schedule.released || schedule.terminated {
+            return Err(Error::ScheduleAlreadyReleased);
+        }
+
+        let mut escrow: Escrow = env.storage().persistent().get(&DataKey::Escrow(bounty_id)).unwrap();
+        if caller != admin && caller != escrow.depositor {
+            return Err(Error::Unauthorized);
+        }
+        caller.require_auth();
+        if escrow.status != EscrowStatus::Locked && escrow.status != EscrowStatus::Partial {
+            return Err(Error::FundsNotLocked);
+        }
+
+        let now = env.ledger().timestamp();
+        let vested_payout = if schedule.is_vesting {
+            vested_amount(now, &schedule) - schedule.released_amount
+        } else {
+            0
+        };
+        let refund_amount = schedule.amount - schedule.released_amount - vested_payout;
+
+        guard_enter(&env, bounty_id)?;
+
+        // Checks-effects-interactions: persist the schedule and escrow state
+        // before the external token transfers so a reentrant call sees the
+        // schedule already terminated and the escrow already updated.
+        schedule.terminated = true;
+        schedule.released_amount += vested_payout;
+        schedules.set(index, schedule.clone());
+        env.storage().persistent().set(&DataKey::Schedules(bounty_id), &schedules);
+
+        escrow.released_amount += vested_payout + refund_amount;
+        let new_status = if escrow.released_amount == escrow.amount {
+            EscrowStatus::Released
+        } else {
+            EscrowStatus::Partial
+        };
+        index_transition(&env, bounty_id, &escrow.status, &new_status);
+        escrow.status = new_status;
+        env.storage().persistent().set(&DataKey::Escrow(bounty_id), &escrow);
+
+        let token_addr: Address = env.storage().instance().get(&DataKey::Token).unwrap();
+        let client = token::Client::new(&env, &token_addr);
+
+        if vested_payout > 0 {
+            client.transfer(&env.current_contract_address(), &schedule.recipient, &vested_payout);
+            record_history(&env, bounty_id, EscrowEventKind::Release, vested_payout, schedule.recipient.clone());
+            record_schedule_release(&env, bounty_id, schedule_id, vested_payout, schedule.recipient.clone(), ReleaseType::Vested, Vec::new(&env));
+        }
+        if refund_amount > 0 {
+            client.transfer(&env.current_contract_address(), &escrow.depositor, &refund_amount);
+            record_history(&env, bounty_id, EscrowEventKind::Refund, refund_amount, escrow.depositor.clone());
+            record_schedule_release(&env, bounty_id, schedule_id, refund_amount, escrow.depositor.clone(), ReleaseType::Terminated, Vec::new(&env));
+        }
+
+        guard_exit(&env, bounty_id);
+        Ok(refund_amount)
+    }
+
+    /// Releases every due, fixed-timestamp `ReleaseSchedule` for a bounty in
+    /// one call, as a batch alternative to calling
+    /// `release_schedule_automatic` once per schedule.
+    ///
+    /// Vesting schedules are untouched here; their recipient must pull their
+    /// balance via `claim_vested`. Idempotent: a schedule already `released`
+    /// or `terminated`, or whose `release_timestamp` hasn't passed, is
+    /// silently skipped rather than erroring the whole batch. Transfers are
+    /// aggregated per distinct recipient to save cross-contract calls, but
+    /// `get_release_history` still gets one `Automatic` entry per schedule.
+    ///
+    /// # Authorization
+    /// **Permissionless**, like `release_schedule_automatic`.
+    ///
+    /// # Returns
+    /// * `Ok(schedule_ids)` - IDs of the schedules paid out by this call, in
+    ///   creation order; empty if none were due
+    /// * `Err(Error::BountyNotFound)` - No schedule exists for this bounty
+    /// * `Err(Error::FundsNotLocked)` - Escrow isn't `Locked` or `Partial`
+    pub fn release_due_schedules(env: Env, bounty_id: u64) -> Result<Vec<u32>, Error> {
+        if !env.storage().persistent().has(&DataKey::Schedules(bounty_id)) {
+            return Err(Error::BountyNotFound);
+        }
+        let mut escrow: Escrow = env.storage().persistent().get(&DataKey::Escrow(bounty_id)).unwrap();
+        if escrow.status != EscrowStatus::Locked && escrow.status != EscrowStatus::Partial {
+            return Err(Error::FundsNotLocked);
+        }
+
+        // Unwind any active stake first so the balance is on hand to release.
+        unstake_if_staked(&env, bounty_id)?;
+
+        let mut schedules: Vec<ReleaseSchedule> =
+            env.storage().persistent().get(&DataKey::Schedules(bounty_id)).unwrap();
+        let now = env.ledger().timestamp();
+        let released_by = env.current_contract_address();
+
+        let mut paid_ids = Vec::new(&env);
+        let mut payouts: Map<Address, i128> = Map::new(&env);
+        for i in 0..schedules.len() {
+            let mut schedule = schedules.get(i).unwrap();
+            if schedule.is_vesting
+                || schedule.released
+                || schedule.terminated
+                || now < schedule.release_timestamp
+            {
+                continue;
+            }
+
+            schedule.released = true;
+            schedule.released_at = Some(now);
+            schedule.released_by = Some(released_by.clone());
+            schedule.released_amount = schedule.amount;
+            let recipient = schedule.recipient.clone();
+            let amount = schedule.amount;
+            let schedule_id = schedule.schedule_id;
+            schedules.set(i, schedule);
+
+            let running = payouts.get(recipient.clone()).unwrap_or(0);
+            payouts.set(recipient.clone(), running + amount);
+
+            escrow.released_amount += amount;
+            record_history(&env, bounty_id, EscrowEventKind::Release, amount, recipient.clone());
+            record_schedule_release(&env, bounty_id, schedule_id, amount, recipient, ReleaseType::Automatic, Vec::new(&env));
+            paid_ids.push_back(schedule_id);
+        }
+
+        if paid_ids.is_empty() {
+            return Ok(paid_ids);
+        }
+
+        env.storage().persistent().set(&DataKey::Schedules(bounty_id), &schedules);
+
+        let new_status = if escrow.released_amount == escrow.amount {
+            EscrowStatus::Released
+        } else {
+            EscrowStatus::Partial
+        };
+        index_transition(&env, bounty_id, &escrow.status, &new_status);
+        escrow.status = new_status;
+        env.storage().persistent().set(&DataKey::Escrow(bounty_id), &escrow);
+
+        let token_addr: Address = env.storage().instance().get(&DataKey::Token).unwrap();
+        let client = token::Client::new(&env, &token_addr);
+        for (recipient, amount) in payouts.iter() {
+            client.transfer(&env.current_contract_address(), &recipient, &amount);
+            emit_funds_released(
+                &env,
+                FundsReleased { bounty_id, amount, recipient, timestamp: now },
+            );
+        }
+
+        Ok(paid_ids)
+    }
+
+    /// Deposits a bounty's currently-idle locked balance into an external
+    /// staking pool via cross-contract call, so capital isn't sitting idle
+    /// for the (often very long) duration of `deadline`.
+    ///
+    /// # Arguments
+    /// * `pool` - Address of the external staking-pool contract, invoked via
+    ///   `StakingPoolClient::deposit_and_stake`
+    /// * `beneficiary` - Address credited with any yield earned above the
+    ///   staked principal once `unstake_locked_funds` withdraws it; defaults
+    ///   to the escrow's `depositor` if `None`
+    ///
+    /// # Authorization
+    /// Must be called by the contract admin. If an `approver` is configured
+    /// for this bounty, they alone authorize instead (mirroring
+    /// `release_funds`). Rejected outright once `init_multisig` is
+    /// configured, since `pool` is an arbitrary address the caller
+    /// controls - a single signer must not be able to move the remaining
+    /// principal there without the M-of-N quorum.
+    ///
+    /// # Returns
+    /// * `Err(Error::BountyNotFound)` - Bounty doesn't exist
+    /// * `Err(Error::FundsNotLocked)` - Escrow isn't `Locked` or `Partial`
+    /// * `Err(Error::AlreadyStaked)` - Bounty already has an active stake
+    /// * `Err(Error::Unauthorized)` - Nothing left to stake (fully released)
+    /// * `Err(Error::MultisigRequired)` - Contract was initialized via
+    ///   `init_multisig`; this bounty has no per-escrow `approver` override
+    pub fn stake_locked_funds(
+        env: Env,
+        bounty_id: u64,
+        pool: Address,
+        beneficiary: Option<Address>,
+    ) -> Result<(), Error> {
+        if !env.storage().instance().has(&DataKey::Admin) {
+            return Err(Error::NotInitialized);
+        }
+
         if !env.storage().persistent().has(&DataKey::Escrow(bounty_id)) {
             return Err(Error::BountyNotFound);
         }
+        let escrow: Escrow = env.storage().persistent().get(&DataKey::Escrow(bounty_id)).unwrap();
 
-        // Get and verify escrow state
-        let mut escrow: Escrow = env.storage().persistent().get(&DataKey::Escrow(bounty_id)).unwrap();
+        if let Some(approver) = escrow.approver.clone() {
+            approver.require_auth();
+        } else if env.storage().instance().has(&DataKey::Admins) {
+            return Err(Error::MultisigRequired);
+        } else {
+            let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+            admin.require_auth();
+        }
 
-        if escrow.status != EscrowStatus::Locked {
+        if escrow.status != EscrowStatus::Locked && escrow.status != EscrowStatus::Partial {
             return Err(Error::FundsNotLocked);
         }
 
-        // Transfer funds to contributor
+        let key = DataKey::Stake(bounty_id);
+        if env.storage().persistent().has(&key) {
+            return Err(Error::AlreadyStaked);
+        }
+
+        let principal = escrow.amount - escrow.released_amount;
+        if principal <= 0 {
+            return Err(Error::Unauthorized);
+        }
+
+        guard_enter(&env, bounty_id)?;
+
+        // Checks-effects-interactions: record the stake before the external
+        // token transfer/cross-contract call so a reentrant call sees it.
+        let stake = StakeInfo {
+            pool: pool.clone(),
+            principal,
+            beneficiary: beneficiary.unwrap_or_else(|| escrow.depositor.clone()),
+        };
+        env.storage().persistent().set(&key, &stake);
+
         let token_addr: Address = env.storage().instance().get(&DataKey::Token).unwrap();
         let client = token::Client::new(&env, &token_addr);
-        client.transfer(&env.current_contract_address(), &contributor, &escrow.amount);
+        client.transfer(&env.current_contract_address(), &pool, &principal);
 
-        // Update escrow status
-        escrow.status = EscrowStatus::Released;
-        env.storage().persistent().set(&DataKey::Escrow(bounty_id), &escrow);
+        let pool_client = StakingPoolClient::new(&env, &pool);
+        pool_client.deposit_and_stake(&env.current_contract_address(), &principal);
 
-        // Emit release event
-        emit_funds_released(
-            &env,
-            FundsReleased {
-                bounty_id,
-                amount: escrow.amount,
-                recipient: contributor.clone(),
-                timestamp: env.ledger().timestamp()
-            },
-        );
+        record_history(&env, bounty_id, EscrowEventKind::Staked, principal, pool);
 
+        guard_exit(&env, bounty_id);
         Ok(())
     }
 
+    /// Withdraws a bounty's staked principal plus any accrued rewards from
+    /// its staking pool, crediting yield above principal to the stake's
+    /// `beneficiary` and returning the principal to the escrow's own
+    /// balance so it's available again for `release_funds`/`refund`/etc.
+    ///
+    /// # Authorization
+    /// Must be called by the contract admin.
+    ///
+    /// # Returns
+    /// * `Ok(withdrawn)` - Total amount withdrawn from the pool (principal plus yield)
+    /// * `Err(Error::BountyNotFound)` - Bounty doesn't exist
+    /// * `Err(Error::NotStaked)` - Bounty has no active stake
+    pub fn unstake_locked_funds(env: Env, bounty_id: u64) -> Result<i128, Error> {
+        if !env.storage().instance().has(&DataKey::Admin) {
+            return Err(Error::NotInitialized);
+        }
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        admin.require_auth();
+
+        if !env.storage().persistent().has(&DataKey::Escrow(bounty_id)) {
+            return Err(Error::BountyNotFound);
+        }
+        if !env.storage().persistent().has(&DataKey::Stake(bounty_id)) {
+            return Err(Error::NotStaked);
+        }
+
+        guard_enter(&env, bounty_id)?;
+        let withdrawn = unstake_if_staked(&env, bounty_id)?;
+        guard_exit(&env, bounty_id);
+
+        Ok(withdrawn)
+    }
+
     /// Refunds escrowed funds to the depositor after deadline expiration.
     ///
     /// # Arguments
@@ -723,11 +2925,12 @@ impl BountyEscrowContract {
     /// # Returns
     /// * `Ok(())` - Funds successfully refunded
     /// * `Err(Error::BountyNotFound)` - Bounty doesn't exist
-    /// * `Err(Error::FundsNotLocked)` - Funds not in LOCKED state
+    /// * `Err(Error::FundsNotLocked)` - Funds not in `Locked` or `Partial` state
     /// * `Err(Error::DeadlineNotPassed)` - Current time before deadline
     ///
     /// # State Changes
-    /// - Transfers tokens from contract back to depositor
+    /// - Transfers the unreleased remainder (`amount - released_amount`)
+    ///   from contract back to depositor
     /// - Updates escrow status to Refunded
     /// - Emits FundsRefunded event
     ///
@@ -755,7 +2958,7 @@ impl BountyEscrowContract {
     /// ```rust
     /// // Deadline was January 1, 2025
     /// // Current time: January 15, 2025
-    /// 
+    ///
     /// // Anyone can call refund now
     /// escrow_client.refund(&42)?;
     /// // Funds returned to original depositor
@@ -768,9 +2971,9 @@ impl BountyEscrowContract {
     /// ```rust
     /// // Set deadline for 30 days from now
     /// let deadline = env.ledger().timestamp() + (30 * 24 * 60 * 60);
-    /// 
-    /// // After deadline passes, refund becomes available
-    /// // Current time must be > deadline
+    ///
+    /// // Once the deadline is reached, refund becomes available
+    /// // Current time must be >= deadline
     /// ```
     pub fn refund(env: Env, bounty_id: u64) -> Result<(), Error> {
         // Verify bounty exists
@@ -781,7 +2984,7 @@ impl BountyEscrowContract {
         // Get and verify escrow state
         let mut escrow: Escrow = env.storage().persistent().get(&DataKey::Escrow(bounty_id)).unwrap();
 
-        if escrow.status != EscrowStatus::Locked {
+        if escrow.status != EscrowStatus::Locked && escrow.status != EscrowStatus::Partial {
             return Err(Error::FundsNotLocked);
         }
 
@@ -791,26 +2994,157 @@ impl BountyEscrowContract {
             return Err(Error::DeadlineNotPassed);
         }
 
-        // Transfer funds back to depositor
-        let token_addr: Address = env.storage().instance().get(&DataKey::Token).unwrap();
-        let client = token::Client::new(&env, &token_addr);
-        client.transfer(&env.current_contract_address(), &escrow.depositor, &escrow.amount);
+        // Only the portion not already paid out via release_partial is refundable
+        let remaining = escrow.amount - escrow.released_amount;
+
+        guard_enter(&env, bounty_id)?;
 
-        // Update escrow status
+        // Checks-effects-interactions: update escrow state before the
+        // external token transfer so a reentrant call sees it already refunded.
+        index_transition(&env, bounty_id, &escrow.status, &EscrowStatus::Refunded);
         escrow.status = EscrowStatus::Refunded;
         env.storage().persistent().set(&DataKey::Escrow(bounty_id), &escrow);
 
+        // Transfer the remaining funds back to depositor
+        let token_addr: Address = env.storage().instance().get(&DataKey::Token).unwrap();
+        let client = token::Client::new(&env, &token_addr);
+        client.transfer(&env.current_contract_address(), &escrow.depositor, &remaining);
+
+        record_history(&env, bounty_id, EscrowEventKind::Refund, remaining, escrow.depositor.clone());
+
         // Emit refund event
         emit_funds_refunded(
             &env,
             FundsRefunded {
                 bounty_id,
-                amount: escrow.amount,
+                amount: remaining,
                 refund_to: escrow.depositor,
                 timestamp: env.ledger().timestamp()
             },
         );
 
+        guard_exit(&env, bounty_id);
+        Ok(())
+    }
+
+    /// Sets (or changes) the social-recovery address for a bounty, and
+    /// refreshes `last_activity` to the current ledger time.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `bounty_id` - The bounty to configure
+    /// * `recovery` - Address that can claim the funds via `recover` after
+    ///   the inactivity window elapses
+    ///
+    /// # Authorization
+    /// Must be called by the escrow's `depositor`.
+    ///
+    /// # Returns
+    /// * `Err(Error::BountyNotFound)` - Bounty doesn't exist
+    /// * `Err(Error::FundsNotLocked)` - Escrow isn't `Locked` or `Partial`
+    pub fn set_recovery_address(env: Env, bounty_id: u64, recovery: Address) -> Result<(), Error> {
+        if !env.storage().persistent().has(&DataKey::Escrow(bounty_id)) {
+            return Err(Error::BountyNotFound);
+        }
+
+        let mut escrow: Escrow = env.storage().persistent().get(&DataKey::Escrow(bounty_id)).unwrap();
+        escrow.depositor.require_auth();
+
+        if escrow.status != EscrowStatus::Locked && escrow.status != EscrowStatus::Partial {
+            return Err(Error::FundsNotLocked);
+        }
+
+        escrow.recovery = Some(recovery);
+        escrow.last_activity = env.ledger().timestamp();
+        env.storage().persistent().set(&DataKey::Escrow(bounty_id), &escrow);
+        Ok(())
+    }
+
+    /// Sets the contract-wide inactivity window (in seconds) that `recover`
+    /// waits for after an escrow's `last_activity` before releasing funds.
+    ///
+    /// # Authorization
+    /// Must be called by the contract admin.
+    pub fn set_recovery_window(env: Env, seconds: u64) -> Result<(), Error> {
+        if !env.storage().instance().has(&DataKey::Admin) {
+            return Err(Error::NotInitialized);
+        }
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        admin.require_auth();
+
+        env.storage().instance().set(&DataKey::RecoveryWindow, &seconds);
+        Ok(())
+    }
+
+    /// Returns `(recovery, last_activity)` for a bounty.
+    ///
+    /// # Returns
+    /// * `Err(Error::BountyNotFound)` - Bounty doesn't exist
+    pub fn get_recovery_config(env: Env, bounty_id: u64) -> Result<(Option<Address>, u64), Error> {
+        let escrow: Escrow = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Escrow(bounty_id))
+            .ok_or(Error::BountyNotFound)?;
+        Ok((escrow.recovery, escrow.last_activity))
+    }
+
+    /// Transfers a stuck bounty's remaining funds to its configured
+    /// `recovery` address once the inactivity window has elapsed.
+    ///
+    /// # Returns
+    /// * `Err(Error::BountyNotFound)` - Bounty doesn't exist
+    /// * `Err(Error::FundsNotLocked)` - Escrow isn't `Locked` or `Partial`
+    /// * `Err(Error::RecoveryNotConfigured)` - No `recovery` address set
+    /// * `Err(Error::RecoveryNotDue)` - Inactivity window hasn't elapsed yet
+    ///
+    /// # Authorization
+    /// Permissionless: anyone can trigger recovery once it's due, mirroring
+    /// the permissionless design of `refund`.
+    ///
+    /// # Events
+    /// Emits: `FundsRecovered { bounty_id, amount, recovery_to, timestamp }`
+    pub fn recover(env: Env, bounty_id: u64) -> Result<(), Error> {
+        if !env.storage().persistent().has(&DataKey::Escrow(bounty_id)) {
+            return Err(Error::BountyNotFound);
+        }
+
+        let mut escrow: Escrow = env.storage().persistent().get(&DataKey::Escrow(bounty_id)).unwrap();
+
+        if escrow.status != EscrowStatus::Locked && escrow.status != EscrowStatus::Partial {
+            return Err(Error::FundsNotLocked);
+        }
+
+        let recovery = escrow.recovery.clone().ok_or(Error::RecoveryNotConfigured)?;
+
+        let window: u64 = env
+            .storage()
+            .instance()
+            .get(&DataKey::RecoveryWindow)
+            .unwrap_or(DEFAULT_RECOVERY_WINDOW);
+        let now = env.ledger().timestamp();
+        if now < escrow.last_activity + window {
+            return Err(Error::RecoveryNotDue);
+        }
+
+        guard_enter(&env, bounty_id)?;
+
+        let remaining = escrow.amount - escrow.released_amount;
+        index_transition(&env, bounty_id, &escrow.status, &EscrowStatus::Refunded);
+        escrow.status = EscrowStatus::Refunded;
+        env.storage().persistent().set(&DataKey::Escrow(bounty_id), &escrow);
+
+        let token_addr: Address = env.storage().instance().get(&DataKey::Token).unwrap();
+        let client = token::Client::new(&env, &token_addr);
+        client.transfer(&env.current_contract_address(), &recovery, &remaining);
+
+        record_history(&env, bounty_id, EscrowEventKind::Refund, remaining, recovery.clone());
+        env.events().publish(
+            (symbol_short!("FundsRecv"),),
+            (bounty_id, remaining, recovery, now),
+        );
+
+        guard_exit(&env, bounty_id);
         Ok(())
     }
 
@@ -946,6 +3280,367 @@ impl BountyEscrowContract {
         let client = token::Client::new(&env, &token_addr);
         Ok(client.balance(&env.current_contract_address()))
     }
+
+    /// Returns a bounded page of escrows in a given status, with their metadata.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `status` - The `EscrowStatus` to filter by
+    /// * `start` - Index into the status index to start from (0-based)
+    /// * `limit` - Maximum number of entries to return (bounded to 100)
+    ///
+    /// # Returns
+    /// `(page, next_cursor)` where `next_cursor` is `Some(start + page.len())` if
+    /// more entries remain, or `None` once the index is exhausted.
+    pub fn list_bounties(
+        env: Env,
+        status: EscrowStatus,
+        start: u32,
+        limit: u32,
+    ) -> (Vec<EscrowWithMetadata>, Option<u32>) {
+        let bounded_limit = limit.min(100);
+        let ids: Vec<u64> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::StatusIndex(status))
+            .unwrap_or(Vec::new(&env));
+
+        let mut page = Vec::new(&env);
+        let mut i = start;
+        while i < ids.len() && (i - start) < bounded_limit {
+            let bounty_id = ids.get(i).unwrap();
+            if let Ok(view) = Self::get_escrow_with_metadata(env.clone(), bounty_id) {
+                page.push_back(view);
+            }
+            i += 1;
+        }
+
+        let next_cursor = if i < ids.len() { Some(i) } else { None };
+        (page, next_cursor)
+    }
+
+    /// Returns the number of bounties currently in each `EscrowStatus`.
+    ///
+    /// Iterates `ALL_ESCROW_STATUSES`, a compile-time list of every variant,
+    /// so newly added statuses are automatically included without touching
+    /// this function.
+    pub fn counts_by_status(env: Env) -> Map<EscrowStatus, u32> {
+        let mut counts = Map::new(&env);
+        for status in ALL_ESCROW_STATUSES {
+            let ids: Vec<u64> = env
+                .storage()
+                .persistent()
+                .get(&DataKey::StatusIndex(status.clone()))
+                .unwrap_or(Vec::new(&env));
+            counts.set(status, ids.len());
+        }
+        counts
+    }
+
+    /// Returns a bounded page of bare bounty IDs in a given status, without
+    /// the metadata lookups `list_bounties` performs per entry.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `status` - The `EscrowStatus` to filter by
+    /// * `start` - Index into the status index to start from (0-based)
+    /// * `limit` - Maximum number of entries to return (bounded to 100)
+    pub fn list_bounties_by_status(
+        env: Env,
+        status: EscrowStatus,
+        start: u32,
+        limit: u32,
+    ) -> Vec<u64> {
+        let bounded_limit = limit.min(100);
+        let ids: Vec<u64> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::StatusIndex(status))
+            .unwrap_or(Vec::new(&env));
+
+        let mut page = Vec::new(&env);
+        let mut i = start;
+        while i < ids.len() && (i - start) < bounded_limit {
+            page.push_back(ids.get(i).unwrap());
+            i += 1;
+        }
+        page
+    }
+
+    /// Returns the number of bounties currently in a single `EscrowStatus`.
+    /// Prefer `counts_by_status` when counts for every status are needed.
+    pub fn count_by_status(env: Env, status: EscrowStatus) -> u32 {
+        let ids: Vec<u64> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::StatusIndex(status))
+            .unwrap_or(Vec::new(&env));
+        ids.len()
+    }
+
+    /// Returns every `EscrowStatus` variant, derived from the same
+    /// compile-time `ALL_ESCROW_STATUSES` list `counts_by_status` iterates,
+    /// so it automatically covers any status added in the future.
+    pub fn list_all_statuses(env: Env) -> Vec<EscrowStatus> {
+        let mut statuses = Vec::new(&env);
+        for status in ALL_ESCROW_STATUSES {
+            statuses.push_back(status);
+        }
+        statuses
+    }
+
+    /// Returns the complete fund-movement history recorded for a bounty.
+    ///
+    /// # Returns
+    /// * `Err(Error::BountyNotFound)` - No history recorded for this bounty
+    pub fn get_history(env: Env, bounty_id: u64) -> Result<Vec<EscrowEvent>, Error> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::History(bounty_id))
+            .ok_or(Error::BountyNotFound)
+    }
+
+    /// Returns a bounded page of a bounty's fund-movement history.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `bounty_id` - The bounty whose history to page through
+    /// * `start` - Index into the history to start from (0-based)
+    /// * `limit` - Maximum number of entries to return (bounded to 100)
+    ///
+    /// # Returns
+    /// `(page, next_cursor)` where `next_cursor` is `Some(start + page.len())` if
+    /// more entries remain, or `None` once the history is exhausted.
+    pub fn get_history_page(
+        env: Env,
+        bounty_id: u64,
+        start: u32,
+        limit: u32,
+    ) -> (Vec<EscrowEvent>, Option<u32>) {
+        let bounded_limit = limit.min(100);
+        let history: Vec<EscrowEvent> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::History(bounty_id))
+            .unwrap_or(Vec::new(&env));
+
+        let mut page = Vec::new(&env);
+        let mut i = start;
+        while i < history.len() && (i - start) < bounded_limit {
+            page.push_back(history.get(i).unwrap());
+            i += 1;
+        }
+
+        let next_cursor = if i < history.len() { Some(i) } else { None };
+        (page, next_cursor)
+    }
+
+    /// Returns a single `ReleaseSchedule` by its `schedule_id`.
+    ///
+    /// # Returns
+    /// * `Err(Error::ScheduleNotFound)` - No schedule with this ID exists for the bounty
+    pub fn get_release_schedule(env: Env, bounty_id: u64, schedule_id: u32) -> Result<ReleaseSchedule, Error> {
+        let schedules: Vec<ReleaseSchedule> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Schedules(bounty_id))
+            .unwrap_or(Vec::new(&env));
+        let index = find_schedule_index(&schedules, schedule_id).ok_or(Error::ScheduleNotFound)?;
+        Ok(schedules.get(index).unwrap())
+    }
+
+    /// Returns every `ReleaseSchedule` created for a bounty, released or not.
+    pub fn get_all_release_schedules(env: Env, bounty_id: u64) -> Vec<ReleaseSchedule> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Schedules(bounty_id))
+            .unwrap_or(Vec::new(&env))
+    }
+
+    /// Returns every `ReleaseSchedule` not yet fully paid out (`released ==
+    /// false`) and not cancelled via `terminate_schedule`.
+    pub fn get_pending_schedules(env: Env, bounty_id: u64) -> Vec<ReleaseSchedule> {
+        let schedules: Vec<ReleaseSchedule> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Schedules(bounty_id))
+            .unwrap_or(Vec::new(&env));
+        let mut pending = Vec::new(&env);
+        for schedule in schedules.iter() {
+            if !schedule.released && !schedule.terminated {
+                pending.push_back(schedule);
+            }
+        }
+        pending
+    }
+
+    /// Returns every pending `ReleaseSchedule` with a new claimable amount
+    /// right now: a fixed schedule whose `release_timestamp` has passed, or
+    /// a vesting schedule that has vested past what's already been claimed.
+    pub fn get_due_schedules(env: Env, bounty_id: u64) -> Vec<ReleaseSchedule> {
+        let schedules: Vec<ReleaseSchedule> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Schedules(bounty_id))
+            .unwrap_or(Vec::new(&env));
+        let now = env.ledger().timestamp();
+        let mut due = Vec::new(&env);
+        for schedule in schedules.iter() {
+            if schedule_is_due(now, &schedule) {
+                due.push_back(schedule);
+            }
+        }
+        due
+    }
+
+    /// Returns the complete `ReleaseSchedule` payout history for a bounty.
+    pub fn get_release_history(env: Env, bounty_id: u64) -> Vec<ScheduleReleaseEvent> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::ScheduleHistory(bounty_id))
+            .unwrap_or(Vec::new(&env))
+    }
+}
+
+// ============================================================================
+// Escrow Builder
+// ============================================================================
+
+/// Fluent builder for creating escrows with optional fields, as an
+/// ergonomic alternative to the positional `lock_funds`/`lock_funds_with_hash`/
+/// `lock_funds_with_approver` entrypoints. The low-level functions remain
+/// available for simple, single-field cases.
+///
+/// # Example
+/// ```rust
+/// let bounty_id = EscrowBuilder::new(depositor, amount, deadline)
+///     .approver(approver_address)
+///     .recovery(recovery_address)
+///     .build(env)?;
+/// ```
+pub struct EscrowBuilder {
+    depositor: Address,
+    amount: i128,
+    deadline: u64,
+    approver: Option<Address>,
+    metadata: Option<EscrowMetadata>,
+    milestones: Option<Vec<Milestone>>,
+    recovery: Option<Address>,
+}
+
+impl EscrowBuilder {
+    /// Starts a builder for a bounty with the required fields.
+    pub fn new(depositor: Address, amount: i128, deadline: u64) -> Self {
+        EscrowBuilder {
+            depositor,
+            amount,
+            deadline,
+            approver: None,
+            metadata: None,
+            milestones: None,
+            recovery: None,
+        }
+    }
+
+    /// Sets a third-party approver, enabling the dispute/arbitration workflow.
+    pub fn approver(mut self, approver: Address) -> Self {
+        self.approver = Some(approver);
+        self
+    }
+
+    /// Attaches descriptive metadata, set via the same path as `set_escrow_metadata`.
+    pub fn metadata(mut self, metadata: EscrowMetadata) -> Self {
+        self.metadata = Some(metadata);
+        self
+    }
+
+    /// Sets up a milestone release schedule; amounts must sum to `amount`.
+    pub fn milestones(mut self, milestones: Vec<Milestone>) -> Self {
+        self.milestones = Some(milestones);
+        self
+    }
+
+    /// Sets a social-recovery address for stuck-deposit protection.
+    pub fn recovery(mut self, recovery: Address) -> Self {
+        self.recovery = Some(recovery);
+        self
+    }
+
+    /// Validates the accumulated invariants, locks the funds, and returns
+    /// the auto-assigned `bounty_id`.
+    ///
+    /// # Returns
+    /// * `Err(Error::NotInitialized)` - Contract not initialized
+    /// * `Err(Error::Unauthorized)` - `amount` isn't positive, `deadline` isn't
+    ///   in the future, or `milestones` amounts don't sum to `amount`
+    /// * `Err(Error::MetadataTooLarge)` - `metadata` exceeds size limits
+    pub fn build(self, env: Env) -> Result<u64, Error> {
+        if !env.storage().instance().has(&DataKey::Admin) {
+            return Err(Error::NotInitialized);
+        }
+
+        if self.amount <= 0 || self.deadline <= env.ledger().timestamp() {
+            return Err(Error::Unauthorized);
+        }
+
+        if let Some(milestones) = &self.milestones {
+            let mut total: i128 = 0;
+            for milestone in milestones.iter() {
+                total += milestone.amount;
+            }
+            if total != self.amount {
+                return Err(Error::Unauthorized);
+            }
+        }
+
+        if let Some(metadata) = &self.metadata {
+            if !validate_metadata_size(&env, metadata) {
+                return Err(Error::MetadataTooLarge);
+            }
+        }
+
+        self.depositor.require_auth();
+
+        let bounty_id = next_bounty_id(&env);
+
+        let token_addr: Address = env.storage().instance().get(&DataKey::Token).unwrap();
+        let client = token::Client::new(&env, &token_addr);
+        client.transfer(&self.depositor, &env.current_contract_address(), &self.amount);
+
+        let escrow = Escrow {
+            depositor: self.depositor.clone(),
+            amount: self.amount,
+            status: EscrowStatus::Locked,
+            deadline: self.deadline,
+            hashlock: None,
+            released_amount: 0,
+            approver: self.approver,
+            recovery: self.recovery,
+            last_activity: env.ledger().timestamp(),
+        };
+        env.storage().persistent().set(&DataKey::Escrow(bounty_id), &escrow);
+        index_add(&env, &escrow.status, bounty_id);
+
+        if let Some(metadata) = self.metadata {
+            env.storage().persistent().set(&DataKey::EscrowMetadata(bounty_id), &metadata);
+        }
+        if let Some(milestones) = self.milestones {
+            env.storage().persistent().set(&DataKey::Milestones(bounty_id), &milestones);
+        }
+
+        record_history(&env, bounty_id, EscrowEventKind::Deposit, self.amount, self.depositor.clone());
+        emit_funds_locked(
+            &env,
+            FundsLocked {
+                bounty_id,
+                amount: self.amount,
+                depositor: self.depositor,
+                deadline: self.deadline,
+            },
+        );
+
+        Ok(bounty_id)
+    }
 }
 
 #[cfg(test)]
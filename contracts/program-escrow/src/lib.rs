@@ -3,16 +3,36 @@
 //!
 //! A secure escrow system for managing hackathon and program prize pools on Stellar.
 //! This contract enables organizers to lock funds and distribute prizes to multiple
-//! winners through secure, auditable batch payouts.
+//! winners through secure, auditable, multisig-gated payouts.
 //!
 //! ## Overview
 //!
 //! The Program Escrow contract manages the complete lifecycle of hackathon/program prizes:
-//! 1. **Initialization**: Set up program with authorized payout controller
-//! 2. **Fund Locking**: Lock prize pool funds in escrow
-//! 3. **Batch Payouts**: Distribute prizes to multiple winners simultaneously
-//! 4. **Single Payouts**: Distribute individual prizes
-//! 5. **Tracking**: Maintain complete payout history and balance tracking
+//! 1. **Initialization**: Set up program with a quorum of payout signers
+//! 2. **Fund Locking**: Lock prize pool funds in escrow, one token at a time
+//!    via `lock_program_funds(funder, token, amount)`, which pulls the
+//!    tokens itself via `transfer_from` so accounting and movement happen
+//!    atomically; a single program can hold
+//!    USDC, XLM, and a project's own token side by side, each tracked in its
+//!    own `TokenBalance` entry
+//! 3. **Payout Proposals**: Any signer proposes a batch of recipients/amounts
+//!    for one of the program's funded tokens
+//! 4. **Approval & Queuing**: Signers approve; once `threshold` is reached the
+//!    amounts are escrowed into per-recipient `PendingPayout` entries
+//! 5. **Dispute Window**: The organizer can cancel a wrong entry via
+//!    `dispute_payout` before it's claimed
+//! 6. **Claim**: After `DISPUTE_WINDOW_SECONDS`, the recipient (or the
+//!    backend) calls `claim_payout`, which splits the transfer between the
+//!    recipient (net of `fee_bps`) and the `treasury` (the fee)
+//! 7. **Tracking**: Maintain complete payout history and balance tracking
+//! 8. **Vesting (alternate path)**: For milestone/grant programs, the
+//!    organizer can instead call `schedule_vested_payout` to set up a
+//!    per-recipient tranche schedule, drawn down over time via
+//!    `claim_vested` as each tranche's `release_at` passes
+//! 9. **Idempotent Backend Payouts (alternate path)**: The backend can call
+//!    `batch_payout_idempotent` with a caller-chosen `request_id` to pay a
+//!    batch directly, bypassing the quorum/dispute flow; retrying with the
+//!    same id after a timeout is a safe no-op
 //!
 //! ## Architecture
 //!
@@ -25,7 +45,7 @@
 //! │  │  Organizer   │                                               │
 //! │  └──────┬───────┘                                               │
 //! │         │                                                        │
-//! │         │ 1. init_program()                                     │
+//! │         │ 1. init_program(signers, threshold)                   │
 //! │         ▼                                                        │
 //! │  ┌──────────────────┐                                           │
 //! │  │  Program Created │                                           │
@@ -41,16 +61,21 @@
 //! │           │ 3. Hackathon happens...                             │
 //! │           │                                                      │
 //! │  ┌────────▼─────────┐                                           │
-//! │  │ Authorized       │                                           │
-//! │  │ Payout Key       │                                           │
+//! │  │ Signer Quorum    │                                           │
+//! │  │ (threshold-of-N) │                                           │
 //! │  └────────┬─────────┘                                           │
 //! │           │                                                      │
-//! │    ┌──────┴───────┐                                             │
-//! │    │              │                                             │
-//! │    ▼              ▼                                             │
-//! │ batch_payout() single_payout()                                  │
-//! │    │              │                                             │
-//! │    ▼              ▼                                             │
+//! │     propose_payout() ──▶ approve_payout() × threshold           │
+//! │           │                       │                             │
+//! │           │                       ▼ (auto, or explicit           │
+//! │           │                 execute_payout() queues entries)     │
+//! │           ▼                       ▼                             │
+//! │ ┌─────────────────────────┐    dispute_payout() (organizer,      │
+//! │ │ PendingPayout × N       │     before it's claimed)             │
+//! │ │ (dispute window open)   │                                     │
+//! │ └────────────┬────────────┘                                    │
+//! │              │ release_after elapses                           │
+//! │              ▼ claim_payout() (recipient or backend)            │
 //! │ ┌─────────────────────────┐                                    │
 //! │ │   Winner 1, 2, 3, ...   │                                    │
 //! │ └─────────────────────────┘                                    │
@@ -59,11 +84,30 @@
 //! │  ┌──────────────────────────────────────────┐                  │
 //! │  │ ProgramData:                             │                  │
 //! │  │  - program_id                            │                  │
-//! │  │  - total_funds                           │                  │
-//! │  │  - remaining_balance                     │                  │
+//! │  │  - balances: Map<token, TokenBalance>    │                  │
+//! │  │    (total_funds / remaining_balance per  │                  │
+//! │  │     funded token)                        │                  │
 //! │  │  - authorized_payout_key                 │                  │
-//! │  │  - payout_history: [PayoutRecord]        │                  │
-//! │  │  - token_address                         │                  │
+//! │  │  - last_key_rotation                     │                  │
+//! │  │  - signers / threshold                   │                  │
+//! │  │  - proposal_window_seconds               │                  │
+//! │  │  - payout_count                          │                  │
+//! │  │  - chain_head                            │                  │
+//! │  │  - fee_bps / treasury                    │                  │
+//! │  │  - total_fees_collected                  │                  │
+//! │  │ PayoutProposal (keyed by id):             │                  │
+//! │  │  - token / recipients / amounts /         │                  │
+//! │  │    approvals                              │                  │
+//! │  │ PendingPayout (keyed by id):              │                  │
+//! │  │  - token / recipient / amount /           │                  │
+//! │  │    release_after / disputed / claimed     │                  │
+//! │  │ VestedSchedule (keyed by recipient):      │                  │
+//! │  │  - token / total / tranches:              │                  │
+//! │  │    [VestingTranche]                       │                  │
+//! │  │ DataKey::Payout(index) (each its own key): │                  │
+//! │  │  - one PayoutRecord per index, 0..payout_count │             │
+//! │  │ Processed request ids (each its own key): │                  │
+//! │  │  - request_id -> true, TTL-extended        │                  │
 //! │  └──────────────────────────────────────────┘                  │
 //! └─────────────────────────────────────────────────────────────────┘
 //! ```
@@ -71,47 +115,101 @@
 //! ## Security Model
 //!
 //! ### Trust Assumptions
-//! - **Authorized Payout Key**: Trusted backend service that triggers payouts
+//! - **Signer Quorum**: `threshold`-of-N signers are trusted collectively;
+//!   no single signer can trigger a payout alone
 //! - **Organizer**: Trusted to lock appropriate prize amounts
 //! - **Token Contract**: Standard Stellar Asset Contract (SAC)
 //! - **Contract**: Trustless; operates according to programmed rules
 //!
 //! ### Key Security Features
 //! 1. **Single Initialization**: Prevents program re-configuration
-//! 2. **Authorization Checks**: Only authorized key can trigger payouts
-//! 3. **Balance Validation**: Prevents overdrafts
-//! 4. **Atomic Transfers**: All-or-nothing batch operations
-//! 5. **Complete Audit Trail**: Full payout history tracking
-//! 6. **Overflow Protection**: Safe arithmetic for all calculations
+//! 2. **Threshold Authorization**: Payouts require `threshold` distinct signer
+//!    approvals, not a single key
+//! 3. **Proposal Expiry**: Proposals older than `proposal_window_seconds`
+//!    can no longer execute, so a stale quorum can't drain a refilled balance
+//! 4. **Dispute Window**: A reached-threshold payout escrows into a
+//!    `PendingPayout` rather than transferring immediately, giving the
+//!    organizer `DISPUTE_WINDOW_SECONDS` to cancel a wrong winner address
+//! 5. **Balance Validation**: Prevents overdrafts; a disputed entry's amount
+//!    is returned to `remaining_balance` exactly once, never double-counted
+//! 6. **Atomic Transfers**: All-or-nothing batch operations
+//! 7. **Complete Audit Trail**: Full payout history tracking, chained via
+//!    `chain_head`
+//! 8. **Overflow Protection**: Safe arithmetic for all calculations
+//! 9. **Key Rotation & Revocation**: `authorized_payout_key` can be rotated
+//!    or immediately revoked (frozen to a sentinel) if it's suspected
+//!    compromised, gated by a cooldown so a leaked key can't repeatedly
+//!    rotate itself without leaving an auditable `KeyRotated` event trail
+//! 10. **Protocol Fee Integrity**: `fee_bps` is validated `<= 10_000` once at
+//!     `init_program` and never changes afterward; the recipient and
+//!     `treasury` transfers happen atomically inside `claim_payout`, so a
+//!     fee can never be skimmed without the matching payout occurring
+//! 11. **Vesting Schedule Validation**: `schedule_vested_payout` rejects a
+//!     schedule whose tranches don't sum to `total`, whose `release_at`
+//!     timestamps aren't strictly increasing, or whose `total` exceeds the
+//!     balance available at scheduling time
+//! 12. **Idempotency**: `batch_payout_idempotent` records each `request_id`
+//!     it processes in persistent storage before returning, so a retried
+//!     call with the same id is a no-op instead of a double payment
+//! 13. **Per-Token Validation**: Every payout path (`propose_payout`,
+//!     `schedule_vested_payout`, `batch_payout_idempotent`) validates its
+//!     `token` argument against `balances` and rejects an unfunded token
+//!     with `TokenNotFunded`, so one token's balance can never be mistaken
+//!     for another's
+//! 14. **Risk Limits**: `set_payout_limits` lets the organizer cap any one
+//!     recipient's amount, a batch's combined total, and a batch's recipient
+//!     count, independent of the remaining-balance check; `propose_payout`
+//!     and `batch_payout_idempotent` both enforce them, bounding how much a
+//!     compromised `authorized_payout_key` can move in a single call
+//! 15. **Nonce-Ordered Batches**: `batch_payout_idempotent` additionally
+//!     requires its `nonce` argument to equal `payout_nonce`, the expected
+//!     next value; this is a stronger, order-enforcing guarantee layered on
+//!     top of `request_id`'s arbitrary-key dedup, closing the gap where an
+//!     at-least-once off-chain orchestrator resubmits an old signed instruction
+//!     under a fresh `request_id`
+//! 16. **Paginated History**: Each `PayoutRecord` is appended under its own
+//!     `DataKey::Payout(index)` persistent entry instead of being cloned and
+//!     rewritten as part of one ever-growing `ProgramData.payout_history`
+//!     vector, so a payout's storage cost (and the risk of hitting an entry
+//!     size limit) stays constant regardless of how many payouts a
+//!     long-running program has already made
 //!
 //! ## Usage Example
 //!
 //! ```rust
 //! use soroban_sdk::{Address, Env, String, vec};
 //!
-//! // 1. Initialize program (one-time setup)
+//! // 1. Initialize program with a 2-of-3 signer quorum
 //! let program_id = String::from_str(&env, "Hackathon2024");
 //! let backend = Address::from_string("GBACKEND...");
 //! let usdc_token = Address::from_string("CUSDC...");
-//! 
+//! let signers = vec![&env, signer_a, signer_b, signer_c];
+//!
 //! let program = escrow_client.init_program(
 //!     &program_id,
 //!     &backend,
-//!     &usdc_token
+//!     &signers,
+//!     &2u32,
+//!     &86_400u64, // proposals expire after 1 day
+//!     &250u32, // 2.5% protocol fee
+//!     &treasury_address,
 //! );
 //!
-//! // 2. Lock prize pool (10,000 USDC)
+//! // 2. Lock prize pool (10,000 USDC, plus a project token for a side prize)
 //! let prize_pool = 10_000_0000000; // 10,000 USDC (7 decimals)
-//! escrow_client.lock_program_funds(&prize_pool);
+//! token_usdc_client.approve(&organizer, &contract_address, &prize_pool, &expiration_ledger);
+//! escrow_client.lock_program_funds(&organizer, &usdc_token, &prize_pool);
+//! token_project_client.approve(&organizer, &contract_address, &5_000_0000000, &expiration_ledger);
+//! escrow_client.lock_program_funds(&organizer, &project_token, &5_000_0000000);
 //!
-//! // 3. After hackathon, distribute prizes
+//! // 3. After hackathon, a signer proposes the distribution in USDC
 //! let winners = vec![
 //!     &env,
 //!     Address::from_string("GWINNER1..."),
 //!     Address::from_string("GWINNER2..."),
 //!     Address::from_string("GWINNER3..."),
 //! ];
-//! 
+//!
 //! let prizes = vec![
 //!     &env,
 //!     5_000_0000000,  // 1st place: 5,000 USDC
@@ -119,7 +217,18 @@
 //!     2_000_0000000,  // 3rd place: 2,000 USDC
 //! ];
 //!
-//! escrow_client.batch_payout(&winners, &prizes);
+//! let proposal_id = escrow_client.propose_payout(&signer_a, &usdc_token, &winners, &prizes);
+//!
+//! // 4. Proposing is not an approval: two signers' approvals reach the
+//! //    2-of-3 threshold, queuing one PendingPayout per winner
+//! escrow_client.approve_payout(&proposal_id, &signer_a);
+//! escrow_client.approve_payout(&proposal_id, &signer_b);
+//!
+//! // 5. Organizer spots a wrong address and cancels it before it claims
+//! escrow_client.dispute_payout(&bad_pending_payout_id);
+//!
+//! // 6. Once DISPUTE_WINDOW_SECONDS has passed, winners claim their prize
+//! escrow_client.claim_payout(&good_pending_payout_id, &winner_address);
 //! ```
 //!
 //! ## Event System
@@ -127,22 +236,67 @@
 //! The contract emits events for all major operations:
 //! - `ProgramInit`: Program initialization
 //! - `FundsLocked`: Prize funds locked
-//! - `BatchPayout`: Multiple prizes distributed
-//! - `Payout`: Single prize distributed
+//! - `PropPaid`: A payout proposal was created
+//! - `PropAppr`: A signer approved a pending proposal
+//! - `PayQueued`: A reached-threshold proposal's amounts were escrowed
+//! - `PayDispute`: The organizer cancelled a pending payout
+//! - `PayClaimed`: A pending payout's transfer was performed, split into
+//!   the recipient's net amount and the `treasury`'s fee (also emitted per
+//!   recipient by a fresh `batch_payout_idempotent` call)
+//! - `KeyRotated`: The authorized payout key was rotated or revoked
+//! - `TrancheRel`: A vesting tranche was released via `claim_vested`
 //!
 //! ## Best Practices
 //!
-//! 1. **Verify Winners**: Confirm winner addresses off-chain before payout
+//! 1. **Verify Winners**: Confirm winner addresses off-chain before proposing
 //! 2. **Test Payouts**: Use testnet for testing prize distributions
-//! 3. **Secure Backend**: Protect authorized payout key with HSM/multi-sig
+//! 3. **Distribute Signers**: Hold the signer set across independent parties/HSMs
 //! 4. **Audit History**: Review payout history before each distribution
 //! 5. **Balance Checks**: Verify remaining balance matches expectations
-//! 6. **Token Approval**: Ensure contract has token allowance before locking funds
+//! 6. **Token Approval**: `funder` must call the token's `approve` for at
+//!    least `amount` before calling `lock_program_funds`, which pulls the
+//!    tokens itself via `transfer_from`
+//! 7. **Size the Window**: Pick `proposal_window_seconds` long enough for
+//!    signers to respond, short enough that a stale approval can't surprise you
+//! 8. **Rotate on Suspicion**: Call `revoke_payout_key` immediately on any
+//!    suspected backend key leak, then `rotate_payout_key` once a new key
+//!    is provisioned
+//! 9. **Monitor Pending Payouts**: Watch `PayQueued` events and review each
+//!    entry's recipient during `DISPUTE_WINDOW_SECONDS` before it's claimable
+//! 10. **Audit Fees**: Periodically compare `get_total_fees_collected` against
+//!     `treasury`'s on-chain token balance to confirm nothing was skimmed
+//!     outside of `claim_payout`
+//! 11. **Size Vesting Schedules Conservatively**: Validate a schedule's
+//!     `total` against expected future balance, not just the balance at
+//!     scheduling time, since other payouts may consume it first
+//! 12. **Derive Stable Request Ids**: Derive `batch_payout_idempotent`'s
+//!     `request_id` deterministically from the batch's contents (e.g. a
+//!     hash of recipients/amounts/job id), so a genuine retry reuses the
+//!     same id instead of accidentally minting a new one
+//! 13. **Audit Per-Token Balances**: Call `get_balances` rather than
+//!     `get_remaining_balance` for a single token when reconciling a
+//!     multi-token program, so a token you forgot about doesn't go unaudited
+//! 14. **Migrate Once, Early**: Run `migrate_to_multi_token` immediately
+//!     after upgrading a pre-existing single-token program, before locking
+//!     any additional tokens into it
+//! 15. **Set Risk Limits Early**: Call `set_payout_limits` right after
+//!     `init_program`, before any funds are locked, so there's no window
+//!     where a compromised key could propose an unbounded payout
+//! 16. **Track the Next Nonce Off-Chain**: Have the backend read
+//!     `get_program_info`'s `payout_nonce` (or track it locally) before
+//!     signing the next `batch_payout_idempotent` call, rather than
+//!     guessing, since a mismatched nonce always fails closed
+//! 17. **Page Through History**: Use `get_payout_history(start, limit)` with
+//!     `get_payout_count` to audit a long-running program's payouts, rather
+//!     than attempting to reconstruct it from `get_program_info` alone,
+//!     which no longer embeds the full record list
 
 #![no_std]
+mod test_program_escrow;
+
 use soroban_sdk::{
-    contract, contracterror, contractimpl, contracttype, symbol_short, vec, Address, Env, Map, String, Symbol, Vec,
-    token,
+    contract, contracterror, contractimpl, contracttype, symbol_short, vec, Address, Bytes, BytesN, Env, Map, String,
+    Symbol, Vec, token,
 };
 
 // ============================================================================
@@ -159,30 +313,134 @@ use soroban_sdk::{
 /// * `InvalidAmount (5)` - Amount must be greater than zero
 /// * `BatchMismatch (6)` - Recipients and amounts vectors length mismatch
 /// * `MetadataTooLarge (7)` - Metadata exceeds size limits
+/// * `InvalidThreshold (8)` - `threshold` is zero or exceeds the signer count
+/// * `ProposalNotFound (9)` - No payout proposal exists for the given id
+/// * `ProposalExpired (10)` - `proposal_window_seconds` has elapsed since the proposal was created
+/// * `ProposalAlreadyExecuted (11)` - The proposal's payout already executed
+/// * `AlreadyApproved (12)` - This signer already approved the proposal
+/// * `RotationOnCooldown (13)` - `rotate_payout_key` called before `ROTATION_COOLDOWN_SECONDS` elapsed
+/// * `PendingPayoutNotFound (14)` - No pending payout exists for the given id
+/// * `PendingPayoutDisputed (15)` - The pending payout was disputed and cancelled
+/// * `PendingPayoutAlreadyClaimed (16)` - The pending payout was already claimed
+/// * `PendingPayoutNotReleased (17)` - `claim_payout` called before `release_after`
+/// * `InvalidFeeBps (18)` - `init_program`'s `fee_bps` exceeds 10,000 (100%)
+/// * `VestingScheduleExists (19)` - Recipient already has a vesting schedule
+/// * `VestingScheduleNotFound (20)` - No vesting schedule exists for the recipient
+/// * `VestingTotalMismatch (21)` - Tranche amounts don't sum to `total`
+/// * `VestingScheduleNotIncreasing (22)` - Tranche `release_at` timestamps aren't strictly increasing
+/// * `TokenNotFunded (23)` - Named token has never been locked via `lock_program_funds`
+/// * `AlreadyMigrated (24)` - `migrate_to_multi_token` already ran for this program
+/// * `PayoutLimitExceeded (25)` - A proposal or batch exceeded an organizer-set risk limit
+/// * `InvalidNonce (26)` - `batch_payout_idempotent`'s `nonce` didn't match the expected next value
 #[contracterror]
 #[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
 #[repr(u32)]
 pub enum Error {
     /// Returned when attempting to initialize an already initialized program
     AlreadyInitialized = 1,
-    
+
     /// Returned when calling program functions before initialization
     NotInitialized = 2,
-    
+
     /// Returned when attempting payout with insufficient balance
     InsufficientBalance = 3,
-    
-    /// Returned when caller lacks required authorization for the operation
+
+    /// Returned when caller lacks required authorization for the operation.
+    /// Also returned when a signer tries to propose/approve a payout
+    /// without being part of the registered signer set, or when
+    /// `execute_payout` is attempted before `threshold` approvals are in
     Unauthorized = 4,
-    
+
     /// Returned when amount is zero or negative
     InvalidAmount = 5,
-    
+
     /// Returned when recipients and amounts vectors have different lengths
     BatchMismatch = 6,
-    
+
     /// Returned when metadata exceeds size limits
     MetadataTooLarge = 7,
+
+    /// Returned when `init_program`'s `threshold` is zero or greater than
+    /// the number of signers provided
+    InvalidThreshold = 8,
+
+    /// Returned when `approve_payout`/`execute_payout`/`approve_key_rotation`
+    /// names a proposal id that was never created
+    ProposalNotFound = 9,
+
+    /// Returned when a proposal's `proposal_window_seconds` has elapsed;
+    /// it can no longer be approved or executed
+    ProposalExpired = 10,
+
+    /// Returned when a proposal's payout has already executed, or a key
+    /// rotation proposal's key has already been rotated
+    ProposalAlreadyExecuted = 11,
+
+    /// Returned when a signer has already approved this proposal (payout
+    /// or key rotation)
+    AlreadyApproved = 12,
+
+    /// Returned when `rotate_payout_key` is called before
+    /// `ROTATION_COOLDOWN_SECONDS` has elapsed since the last rotation
+    /// or revocation
+    RotationOnCooldown = 13,
+
+    /// Returned when `dispute_payout`/`claim_payout` names a pending
+    /// payout id that was never queued
+    PendingPayoutNotFound = 14,
+
+    /// Returned when `claim_payout` is attempted on an entry the
+    /// organizer already disputed
+    PendingPayoutDisputed = 15,
+
+    /// Returned when `dispute_payout`/`claim_payout` is attempted on an
+    /// entry that was already claimed
+    PendingPayoutAlreadyClaimed = 16,
+
+    /// Returned when `claim_payout` is called before the entry's
+    /// `release_after` timestamp
+    PendingPayoutNotReleased = 17,
+
+    /// Returned when `init_program`'s `fee_bps` exceeds 10,000 (100%)
+    InvalidFeeBps = 18,
+
+    /// Returned when `schedule_vested_payout` is called for a recipient
+    /// that already has a schedule; cancel isn't supported, only one
+    /// schedule per recipient may ever exist
+    VestingScheduleExists = 19,
+
+    /// Returned when `claim_vested`/`get_vested_schedule` names a
+    /// recipient with no scheduled vesting
+    VestingScheduleNotFound = 20,
+
+    /// Returned when `schedule_vested_payout`'s tranche amounts don't sum
+    /// to the given `total`
+    VestingTotalMismatch = 21,
+
+    /// Returned when `schedule_vested_payout`'s tranche `release_at`
+    /// timestamps aren't strictly increasing
+    VestingScheduleNotIncreasing = 22,
+
+    /// Returned when a function names a token that has never been funded
+    /// via `lock_program_funds`
+    TokenNotFunded = 23,
+
+    /// Returned when `migrate_to_multi_token` is called on a program that
+    /// has already migrated
+    AlreadyMigrated = 24,
+
+    /// Returned when a proposal or idempotent batch would exceed
+    /// `max_single_payout`, `max_batch_total`, or `max_batch_recipients`
+    PayoutLimitExceeded = 25,
+
+    /// Returned when `batch_payout_idempotent`'s `nonce` argument doesn't
+    /// equal `payout_nonce`, the expected next value
+    InvalidNonce = 26,
+
+    /// Returned when `claim_payout`/`claim_vested`/`batch_payout_idempotent`
+    /// is re-entered (e.g. from a malicious token's `transfer` callback)
+    /// while one of them is already executing
+    Reentrancy = 27,
 }
 
 // ============================================================================
@@ -197,13 +455,35 @@ const PROGRAM_INITIALIZED: Symbol = symbol_short!("ProgramInit");
 /// Topic: `FundsLocked`
 const FUNDS_LOCKED: Symbol = symbol_short!("FundsLocked");
 
-/// Event emitted when a batch payout is executed.
-/// Topic: `BatchPayout`
-const BATCH_PAYOUT: Symbol = symbol_short!("BatchPayout");
+/// Event emitted when a signer creates a new payout proposal.
+/// Topic: `PropPaid`
+const PAYOUT_PROPOSED: Symbol = symbol_short!("PropPaid");
+
+/// Event emitted when a signer approves a pending payout proposal.
+/// Topic: `PropAppr`
+const PAYOUT_APPROVED: Symbol = symbol_short!("PropAppr");
+
+/// Event emitted when a reached-threshold proposal's amounts are escrowed
+/// into `PendingPayout` entries, pending the dispute window.
+/// Topic: `PayQueued`
+const PAYOUT_QUEUED: Symbol = symbol_short!("PayQueued");
 
-/// Event emitted when a single payout is executed.
-/// Topic: `Payout`
-const PAYOUT: Symbol = symbol_short!("Payout");
+/// Event emitted when the organizer disputes (cancels) a pending payout
+/// before it's claimed.
+/// Topic: `PayDispute`
+const PAYOUT_DISPUTED: Symbol = symbol_short!("PayDispute");
+
+/// Event emitted when a pending payout's transfer is actually performed.
+/// Topic: `PayClaimed`
+const PAYOUT_CLAIMED: Symbol = symbol_short!("PayClaimed");
+
+/// Event emitted when the authorized payout key is rotated or revoked.
+/// Topic: `KeyRotated`
+const KEY_ROTATED: Symbol = symbol_short!("KeyRotated");
+
+/// Event emitted when a vesting tranche is claimed.
+/// Topic: `TrancheRel`
+const TRANCHE_RELEASED: Symbol = symbol_short!("TrancheRel");
 
 // ============================================================================
 // Storage Keys
@@ -217,6 +497,72 @@ const PROGRAM_DATA: Symbol = symbol_short!("ProgramData");
 /// Contains optional metadata for indexing and categorization.
 const PROGRAM_METADATA: Symbol = symbol_short!("ProgramMeta");
 
+/// Storage key for the map of pending/executed payout proposals, keyed by
+/// proposal id.
+const PAYOUT_PROPOSALS: Symbol = symbol_short!("Proposals");
+
+/// Storage key for the map of pending/executed key rotation proposals,
+/// keyed by proposal id. See `propose_key_rotation`/`approve_key_rotation`.
+const KEY_ROTATION_PROPOSALS: Symbol = symbol_short!("KeyProps");
+
+/// Storage key for the next key rotation proposal id to hand out. Kept
+/// separate from `ProgramData` so adding this recovery path doesn't
+/// require migrating every already-initialized program's stored struct.
+const NEXT_KEY_ROTATION_PROPOSAL_ID: Symbol = symbol_short!("NextKeyP");
+
+/// Storage key for the map of escrowed, not-yet-claimed payouts awaiting
+/// the dispute window, keyed by pending payout id.
+const PENDING_PAYOUTS: Symbol = symbol_short!("PendingPay");
+
+/// Storage key for the map of vesting schedules, keyed by recipient.
+const VESTED_SCHEDULES: Symbol = symbol_short!("VestSched");
+
+/// Storage key marking that `migrate_to_multi_token` has already run, so a
+/// second call can be rejected instead of re-wrapping an already-migrated
+/// balance into a fresh single-entry map.
+const MIGRATED_MULTI_TOKEN: Symbol = symbol_short!("Migrated");
+
+/// Storage key marking that `migrate_payout_history` has already run (or
+/// that `migrate_to_multi_token` already produced the paginated shape
+/// directly), so a second call can be rejected instead of re-replaying an
+/// already-migrated history into fresh `DataKey::Payout` entries.
+const MIGRATED_PAGINATED_HISTORY: Symbol = symbol_short!("MigHist");
+
+/// Reentrancy guard, held for the duration of `claim_payout`/`claim_vested`/
+/// `batch_payout_idempotent` - the program's fund-moving entrypoints. A
+/// single program-wide lock is enough since they all read and write the
+/// same `ProgramData`/balance state rather than per-bounty state.
+const REENTRANCY_LOCK: Symbol = symbol_short!("ReentrLk");
+
+// ============================================================================
+// Constants
+// ============================================================================
+
+/// Minimum number of seconds that must elapse between successive calls to
+/// `rotate_payout_key`, so a compromised key can't rotate straight to an
+/// attacker-controlled address without leaving an auditable `KeyRotated`
+/// event window for organizers to notice and intervene. Does not apply to
+/// `revoke_payout_key`, which must take effect immediately.
+const ROTATION_COOLDOWN_SECONDS: u64 = 86_400;
+
+/// Number of seconds a queued payout sits disputable before the recipient
+/// (or the backend) can claim it. Gives the organizer a window to cancel
+/// a wrong winner address before the transfer is irreversible.
+const DISPUTE_WINDOW_SECONDS: u64 = 259_200;
+
+/// Denominator `fee_bps` is expressed against; `fee_bps: 10_000` means a
+/// 100% fee, `fee_bps: 250` means 2.5%.
+const FEE_BPS_DENOMINATOR: i128 = 10_000;
+
+/// Ledger count below which a processed `batch_payout_idempotent` request
+/// id's TTL is extended, once it's accessed again.
+const PROCESSED_REQUEST_TTL_THRESHOLD: u32 = 100_000;
+
+/// Ledger count a processed request id's TTL is extended to whenever it's
+/// bumped, so retried requests within that window are still recognized
+/// as duplicates without holding every id alive forever.
+const PROCESSED_REQUEST_TTL_EXTEND_TO: u32 = 500_000;
+
 // ============================================================================
 // Data Structures
 // ============================================================================
@@ -225,8 +571,24 @@ const PROGRAM_METADATA: Symbol = symbol_short!("ProgramMeta");
 ///
 /// # Fields
 /// * `recipient` - Address that received the payout
-/// * `amount` - Amount transferred (in token's smallest denomination)
+/// * `token` - Token contract the payout was made in; informational only,
+///   not part of the hashchain derivation (see `prev_hash`)
+/// * `amount` - Gross amount the payout was for (in token's smallest
+///   denomination), before the protocol fee; matches the proposal/pending
+///   entry's amount and feeds the hashchain, so it is unaffected by
+///   `fee_bps`. The recipient actually received `amount - fee`.
+/// * `fee` - Portion of `amount` skimmed to `ProgramData.treasury`; `0`
+///   unless `fee_bps` was non-zero at claim time
 /// * `timestamp` - Unix timestamp when payout was executed
+/// * `prev_hash` - Hashchain link: `sha256(chain_head_before || recipient ||
+///   amount.to_be_bytes() || timestamp.to_be_bytes())`, computed against the
+///   `ProgramData.chain_head` as it stood immediately before this payout. This
+///   becomes the new `chain_head` once the record is appended, so replaying
+///   `payout_history` from genesis and recomputing each link lets
+///   `verify_payout_chain` detect any record that was dropped or altered.
+///   Neither the fee nor the token are part of this derivation, so a
+///   single-token, zero-`fee_bps` program reproduces the exact same chain
+///   as before either feature existed.
 ///
 /// # Usage
 /// These records are stored in the payout history to provide a complete
@@ -236,57 +598,361 @@ const PROGRAM_METADATA: Symbol = symbol_short!("ProgramMeta");
 /// ```rust
 /// let record = PayoutRecord {
 ///     recipient: winner_address,
-///     amount: 1000_0000000, // 1000 USDC
+///     token: usdc_token_address,
+///     amount: 1000_0000000, // 1000 USDC gross
+///     fee: 25_0000000,      // 2.5% protocol fee
 ///     timestamp: env.ledger().timestamp(),
+///     prev_hash: chain_head,
 /// };
 /// ```
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct PayoutRecord {
     pub recipient: Address,
+    pub token: Address,
     pub amount: i128,
+    pub fee: i128,
     pub timestamp: u64,
+    pub prev_hash: BytesN<32>,
+}
+
+/// Persistent storage key for one `PayoutRecord`. Each payout gets its own
+/// entry, `DataKey::Payout(index)` for `0 <= index < ProgramData.payout_count`,
+/// so appending a record costs a single new write regardless of how long
+/// the program's history already is, instead of cloning and rewriting an
+/// ever-growing `Vec` on every payout.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum DataKey {
+    Payout(u32),
+}
+
+/// A token's locked/available balance within a multi-token program.
+///
+/// # Fields
+/// * `total_funds` - Total amount of this token locked (cumulative)
+/// * `remaining_balance` - Current available balance of this token for payouts
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TokenBalance {
+    pub total_funds: i128,
+    pub remaining_balance: i128,
 }
 
 /// Complete program state and configuration.
 ///
 /// # Fields
 /// * `program_id` - Unique identifier for the program/hackathon
-/// * `total_funds` - Total amount of funds locked (cumulative)
-/// * `remaining_balance` - Current available balance for payouts
-/// * `authorized_payout_key` - Address authorized to trigger payouts
-/// * `payout_history` - Complete record of all payouts
-/// * `token_address` - Token contract used for transfers
+/// * `balances` - Per-token locked/available balance, keyed by token
+///   contract address; a token must have an entry here (created by
+///   `lock_program_funds`) before it can be proposed, scheduled, or paid out
+/// * `authorized_payout_key` - Address authorized to manage program metadata
+/// * `last_key_rotation` - `env.ledger().timestamp()` of the last
+///   `rotate_payout_key`/`revoke_payout_key` call, or `0` if the key has
+///   never been rotated. Gates the `ROTATION_COOLDOWN_SECONDS` cooldown.
+/// * `payout_count` - Total number of payouts ever recorded, across every
+///   token; each one lives at its own persistent key `DataKey::Payout(index)`
+///   for `0 <= index < payout_count`, read a page at a time via
+///   `get_payout_history` rather than loaded all at once
+/// * `chain_head` - Current head of the payout hashchain; all-zero bytes
+///   until the first payout. See `PayoutRecord::prev_hash` for how it's
+///   derived and `verify_payout_chain` for how to audit it off-chain.
+/// * `signers` - Addresses authorized to propose/approve payouts, set once
+///   at `init_program` and immutable afterward
+/// * `threshold` - Distinct signer approvals required before a proposed
+///   payout executes; `1 <= threshold <= signers.len()`
+/// * `proposal_window_seconds` - How long a proposal stays approvable/executable
+///   after `propose_payout`, measured against `env.ledger().timestamp()`
+/// * `next_proposal_id` - Monotonically increasing counter handed out by
+///   `propose_payout`
+/// * `next_pending_payout_id` - Monotonically increasing counter handed out
+///   when a reached-threshold proposal queues its `PendingPayout` entries
+/// * `fee_bps` - Protocol fee in basis points (of `FEE_BPS_DENOMINATOR`)
+///   skimmed to `treasury` on every `claim_payout`; `0` disables fees
+///   entirely and reproduces pre-fee behavior byte-for-byte
+/// * `treasury` - Address that receives the fee portion of each claimed
+///   payout; ignored when `fee_bps` is `0`
+/// * `total_fees_collected` - Cumulative fees skimmed to `treasury` so far,
+///   across every token, for organizer auditing
+/// * `max_single_payout` - Optional cap on any one recipient's amount within
+///   a proposal or idempotent batch; `None` disables the check. Settable via
+///   `set_payout_limits`.
+/// * `max_batch_total` - Optional cap on a proposal's or idempotent batch's
+///   combined total; `None` disables the check.
+/// * `max_batch_recipients` - Optional cap on the number of recipients in a
+///   single proposal or idempotent batch; `None` disables the check.
+/// * `payout_nonce` - Expected value of the next `batch_payout_idempotent`
+///   call's `nonce` argument; starts at `0` and increments by one on every
+///   successful (non-replayed) call, rejecting out-of-order or replayed
+///   nonces with `Error::InvalidNonce`
 ///
 /// # Storage
 /// Stored in instance storage with key `PROGRAM_DATA`.
 ///
 /// # Invariants
-/// - `remaining_balance <= total_funds` (always)
-/// - `remaining_balance = total_funds - sum(payout_history.amounts)`
-/// - `payout_history` is append-only
-/// - `program_id` and `authorized_payout_key` are immutable after init
+/// - For every entry, `remaining_balance <= total_funds` (always)
+/// - `remaining_balance = total_funds - sum(amounts of all recorded payouts
+///   for this token) - sum(amount of PendingPayout entries for this token
+///   that are neither disputed nor claimed)`; an entry's amount is deducted
+///   the moment it's queued and only restored on dispute, so it is never
+///   double-counted as available
+/// - The paginated payout history (`DataKey::Payout(0..payout_count)`) is
+///   append-only; `payout_count` only ever increases
+/// - `program_id`, `signers` and `threshold` are immutable after init;
+///   `authorized_payout_key` may be rotated, see below
+/// - `chain_head` always equals the `prev_hash` of the most recently
+///   appended payout record (or all-zero bytes if `payout_count` is `0`)
+/// - `authorized_payout_key` may change over time via `rotate_payout_key`/
+///   `revoke_payout_key`; only its *initial* value is set at `init_program`
+/// - `0 <= fee_bps <= FEE_BPS_DENOMINATOR`, immutable after `init_program`
 ///
 /// # Example
 /// ```rust
+/// let mut balances = Map::new(&env);
+/// balances.set(usdc_token_address, TokenBalance { total_funds: 10_000_0000000, remaining_balance: 7_000_0000000 });
+///
 /// let program_data = ProgramData {
 ///     program_id: String::from_str(&env, "Hackathon2024"),
-///     total_funds: 10_000_0000000,
-///     remaining_balance: 7_000_0000000,
+///     balances,
 ///     authorized_payout_key: backend_address,
-///     payout_history: vec![&env],
-///     token_address: usdc_token_address,
+///     last_key_rotation: 0,
+///     payout_count: 0,
+///     chain_head: BytesN::from_array(&env, &[0u8; 32]),
+///     signers: vec![&env, signer_a, signer_b, signer_c],
+///     threshold: 2,
+///     proposal_window_seconds: 86_400,
+///     next_proposal_id: 0,
+///     next_pending_payout_id: 0,
+///     fee_bps: 250, // 2.5%
+///     treasury: treasury_address,
+///     total_fees_collected: 0,
+///     max_single_payout: None,
+///     max_batch_total: None,
+///     max_batch_recipients: None,
+///     payout_nonce: 0,
 /// };
 /// ```
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct ProgramData {
+    pub program_id: String,
+    pub balances: Map<Address, TokenBalance>,
+    pub authorized_payout_key: Address,
+    pub last_key_rotation: u64,
+    pub payout_count: u32,
+    pub chain_head: BytesN<32>,
+    pub signers: Vec<Address>,
+    pub threshold: u32,
+    pub proposal_window_seconds: u64,
+    pub next_proposal_id: u64,
+    pub next_pending_payout_id: u64,
+    pub fee_bps: u32,
+    pub treasury: Address,
+    pub total_fees_collected: i128,
+    pub max_single_payout: Option<i128>,
+    pub max_batch_total: Option<i128>,
+    pub max_batch_recipients: Option<u32>,
+    pub payout_nonce: u64,
+}
+
+/// Pre-chunk3-8 shape of `PayoutRecord`, before a `token` field existed.
+/// Kept only so `migrate_to_multi_token` can decode an already-initialized
+/// program's existing `payout_history`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LegacyPayoutRecord {
+    pub recipient: Address,
+    pub amount: i128,
+    pub fee: i128,
+    pub timestamp: u64,
+    pub prev_hash: BytesN<32>,
+}
+
+/// Pre-chunk3-8 single-token shape of `ProgramData`, kept only so
+/// `migrate_to_multi_token` can read an already-initialized program's
+/// storage and fold its one token into a fresh `balances` map.
+///
+/// Field-for-field identical to the old `ProgramData`; see that type's
+/// history for what each field meant.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LegacyProgramData {
     pub program_id: String,
     pub total_funds: i128,
     pub remaining_balance: i128,
     pub authorized_payout_key: Address,
-    pub payout_history: Vec<PayoutRecord>,
+    pub last_key_rotation: u64,
+    pub payout_history: Vec<LegacyPayoutRecord>,
     pub token_address: Address,
+    pub chain_head: BytesN<32>,
+    pub signers: Vec<Address>,
+    pub threshold: u32,
+    pub proposal_window_seconds: u64,
+    pub next_proposal_id: u64,
+    pub next_pending_payout_id: u64,
+    pub fee_bps: u32,
+    pub treasury: Address,
+    pub total_fees_collected: i128,
+}
+
+/// Pre-chunk4-5 shape of `ProgramData`, from after multi-token support but
+/// before `payout_history` moved from an embedded `Vec` into per-index
+/// persistent storage. Kept only so `migrate_payout_history` can decode an
+/// already-multi-token program's existing embedded history.
+///
+/// Field-for-field identical to the old `ProgramData`; see that type's
+/// history for what each field meant.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LegacyProgramDataV2 {
+    pub program_id: String,
+    pub balances: Map<Address, TokenBalance>,
+    pub authorized_payout_key: Address,
+    pub last_key_rotation: u64,
+    pub payout_history: Vec<PayoutRecord>,
+    pub chain_head: BytesN<32>,
+    pub signers: Vec<Address>,
+    pub threshold: u32,
+    pub proposal_window_seconds: u64,
+    pub next_proposal_id: u64,
+    pub next_pending_payout_id: u64,
+    pub fee_bps: u32,
+    pub treasury: Address,
+    pub total_fees_collected: i128,
+    pub max_single_payout: Option<i128>,
+    pub max_batch_total: Option<i128>,
+    pub max_batch_recipients: Option<u32>,
+    pub payout_nonce: u64,
+}
+
+/// A proposed batch of payouts awaiting signer quorum before it executes.
+///
+/// # Fields
+/// * `id` - Unique proposal id, assigned by `propose_payout`
+/// * `token` - Token contract this batch pays out in; must already have an
+///   entry in `ProgramData.balances`
+/// * `recipients` / `amounts` - The payout to perform once approved; same
+///   pairing convention as the old `batch_payout(recipients, amounts)`
+/// * `approvals` - Distinct signers who have called `approve_payout` on this
+///   proposal so far
+/// * `created_at` - `env.ledger().timestamp()` when `propose_payout` ran
+/// * `expires_at` - `created_at + proposal_window_seconds`; past this point
+///   the proposal can no longer be approved or executed
+/// * `executed` - Whether the proposal's amounts have already been escrowed
+///   into `PendingPayout` entries. The actual token transfers happen later,
+///   per entry, via `claim_payout`.
+///
+/// # Storage
+/// Stored persistently as `Map<u64, PayoutProposal>` under `PAYOUT_PROPOSALS`,
+/// keyed by `id`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PayoutProposal {
+    pub id: u64,
+    pub token: Address,
+    pub recipients: Vec<Address>,
+    pub amounts: Vec<i128>,
+    pub approvals: Vec<Address>,
+    pub created_at: u64,
+    pub expires_at: u64,
+    pub executed: bool,
+}
+
+/// A proposed change to `authorized_payout_key` awaiting signer quorum, as
+/// a recovery path for when the key itself is lost, leaked, or has already
+/// been revoked via `revoke_payout_key` and so can no longer authorize its
+/// own rotation.
+///
+/// # Fields
+/// * `id` - Unique proposal id, assigned by `propose_key_rotation`
+/// * `new_key` - `Some(address)` to rotate to that key, or `None` to revoke
+///   (mirrors `revoke_payout_key`'s sentinel behavior)
+/// * `approvals` - Distinct signers who have called `approve_key_rotation`
+///   on this proposal so far
+/// * `created_at` - `env.ledger().timestamp()` when `propose_key_rotation` ran
+/// * `executed` - Whether `authorized_payout_key` has already been updated
+///   from this proposal
+///
+/// # Storage
+/// Stored persistently as `Map<u64, KeyRotationProposal>` under
+/// `KEY_ROTATION_PROPOSALS`, keyed by `id`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct KeyRotationProposal {
+    pub id: u64,
+    pub new_key: Option<Address>,
+    pub approvals: Vec<Address>,
+    pub created_at: u64,
+    pub executed: bool,
+}
+
+/// A single payout escrowed out of a token's `remaining_balance` once its
+/// proposal reaches quorum, sitting disputable until `release_after`.
+///
+/// # Fields
+/// * `id` - Unique pending payout id, assigned when the proposal executes
+/// * `proposal_id` - The `PayoutProposal` this entry was queued from
+/// * `token` - Token contract this entry pays out in, copied from the
+///   owning `PayoutProposal`
+/// * `recipient` / `amount` - The transfer to perform once claimed
+/// * `release_after` - `env.ledger().timestamp()` after which `claim_payout`
+///   may be called; before this, only `dispute_payout` can act on the entry
+/// * `disputed` - Whether the organizer cancelled this entry before claim
+/// * `claimed` - Whether the transfer has already been performed
+///
+/// # Storage
+/// Stored persistently as `Map<u64, PendingPayout>` under
+/// `PENDING_PAYOUTS`, keyed by `id`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PendingPayout {
+    pub id: u64,
+    pub proposal_id: u64,
+    pub token: Address,
+    pub recipient: Address,
+    pub amount: i128,
+    pub release_after: u64,
+    pub disputed: bool,
+    pub claimed: bool,
+}
+
+/// A single tranche within a recipient's vesting schedule.
+///
+/// # Fields
+/// * `release_at` - `env.ledger().timestamp()` after which `claim_vested`
+///   may release this tranche
+/// * `amount` - Amount released for this tranche once claimed
+/// * `claimed` - Whether this tranche has already been released
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VestingTranche {
+    pub release_at: u64,
+    pub amount: i128,
+    pub claimed: bool,
+}
+
+/// A milestone-based vesting schedule for a single recipient, set up once
+/// by the organizer via `schedule_vested_payout` and drawn down tranche by
+/// tranche via `claim_vested`.
+///
+/// # Fields
+/// * `recipient` - Address the schedule was created for
+/// * `token` - Token contract the schedule pays out in; must already have
+///   an entry in `ProgramData.balances`
+/// * `total` - Sum of all tranche amounts; validated at scheduling time
+/// * `tranches` - Ordered by strictly increasing `release_at`
+///
+/// # Storage
+/// Stored persistently as `Map<Address, VestedSchedule>` under
+/// `VESTED_SCHEDULES`, keyed by `recipient`. Only one schedule may ever
+/// exist per recipient.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VestedSchedule {
+    pub recipient: Address,
+    pub token: Address,
+    pub total: i128,
+    pub tranches: Vec<VestingTranche>,
 }
 
 /// Metadata structure for enhanced program indexing and categorization.
@@ -448,6 +1114,163 @@ fn validate_program_metadata_size(env: &Env, metadata: &ProgramMetadata) -> bool
     serialized_size <= 2048
 }
 
+/// Extends the payout hashchain with one more link.
+///
+/// Computes `sha256(chain_head || recipient || amount.to_be_bytes() ||
+/// timestamp.to_be_bytes())`, binding the new link to both the payout being
+/// recorded and every link that came before it.
+fn next_chain_link(
+    env: &Env,
+    chain_head: &BytesN<32>,
+    recipient: &Address,
+    amount: i128,
+    timestamp: u64,
+) -> BytesN<32> {
+    let mut data = Bytes::new(env);
+    data.append(&Bytes::from(chain_head.clone()));
+    data.append(&env.serialize_to_bytes(recipient));
+    data.append(&Bytes::from_array(env, &amount.to_be_bytes()));
+    data.append(&Bytes::from_array(env, &timestamp.to_be_bytes()));
+    env.crypto().sha256(&data).into()
+}
+
+/// Appends `record` to the paginated payout history under persistent key
+/// `DataKey::Payout(payout_count)`, then returns the incremented count.
+/// Callers write the returned value back into `ProgramData.payout_count`.
+fn append_payout_record(env: &Env, payout_count: u32, record: &PayoutRecord) -> u32 {
+    env.storage()
+        .persistent()
+        .set(&DataKey::Payout(payout_count), record);
+    payout_count + 1
+}
+
+/// Sets the cross-call reentrancy guard, failing if it is already held
+/// (i.e. a fund-moving entrypoint is already executing somewhere up the
+/// call stack).
+fn guard_enter(env: &Env) -> Result<(), Error> {
+    if env.storage().temporary().has(&REENTRANCY_LOCK) {
+        return Err(Error::Reentrancy);
+    }
+    env.storage().temporary().set(&REENTRANCY_LOCK, &true);
+    Ok(())
+}
+
+/// Releases the reentrancy guard set by `guard_enter`.
+fn guard_exit(env: &Env) {
+    env.storage().temporary().remove(&REENTRANCY_LOCK);
+}
+
+/// Loads the pending/executed payout proposals map, or an empty one if no
+/// proposal has ever been created.
+fn load_proposals(env: &Env) -> Map<u64, PayoutProposal> {
+    env.storage()
+        .persistent()
+        .get(&PAYOUT_PROPOSALS)
+        .unwrap_or_else(|| Map::new(env))
+}
+
+/// Loads the pending/executed key rotation proposals map, or an empty one
+/// if no proposal has ever been created.
+fn load_key_rotation_proposals(env: &Env) -> Map<u64, KeyRotationProposal> {
+    env.storage()
+        .persistent()
+        .get(&KEY_ROTATION_PROPOSALS)
+        .unwrap_or_else(|| Map::new(env))
+}
+
+/// Loads the map of escrowed pending payouts, or an empty one if none have
+/// ever been queued.
+fn load_pending_payouts(env: &Env) -> Map<u64, PendingPayout> {
+    env.storage()
+        .persistent()
+        .get(&PENDING_PAYOUTS)
+        .unwrap_or_else(|| Map::new(env))
+}
+
+/// Loads the map of vesting schedules, or an empty one if none have ever
+/// been created.
+fn load_vested_schedules(env: &Env) -> Map<Address, VestedSchedule> {
+    env.storage()
+        .persistent()
+        .get(&VESTED_SCHEDULES)
+        .unwrap_or_else(|| Map::new(env))
+}
+
+/// Executes an already-quorum-reached proposal: escrows its amounts out of
+/// `remaining_balance` into one `PendingPayout` entry per recipient, each
+/// claimable only after `DISPUTE_WINDOW_SECONDS`, and marks the proposal
+/// `executed`. The actual token transfers, hashchain extension, and
+/// `payout_history` append happen later, per entry, in `claim_payout`.
+/// Callers (`approve_payout`, `execute_payout`) are responsible for
+/// verifying the threshold and expiry first.
+fn execute_proposal(env: &Env, proposal_id: u64) -> Result<(), Error> {
+    let mut program_data: ProgramData = env.storage().instance().get(&PROGRAM_DATA).unwrap();
+    let mut proposals = load_proposals(env);
+    let mut proposal = proposals.get(proposal_id).ok_or(Error::ProposalNotFound)?;
+
+    let mut balance = program_data
+        .balances
+        .get(proposal.token.clone())
+        .ok_or(Error::TokenNotFunded)?;
+
+    let mut total_payout: i128 = 0;
+    for amount in proposal.amounts.iter() {
+        total_payout = total_payout
+            .checked_add(amount)
+            .ok_or(Error::InvalidAmount)?;
+    }
+    if total_payout > balance.remaining_balance {
+        return Err(Error::InsufficientBalance);
+    }
+
+    let release_after = env.ledger().timestamp() + DISPUTE_WINDOW_SECONDS;
+    let mut pending = load_pending_payouts(env);
+
+    for (i, recipient) in proposal.recipients.iter().enumerate() {
+        let amount = proposal.amounts.get(i).unwrap();
+        let id = program_data.next_pending_payout_id;
+        program_data.next_pending_payout_id += 1;
+
+        pending.set(
+            id,
+            PendingPayout {
+                id,
+                proposal_id,
+                recipient: recipient.clone(),
+                token: proposal.token.clone(),
+                amount,
+                release_after,
+                disputed: false,
+                claimed: false,
+            },
+        );
+    }
+    env.storage().persistent().set(&PENDING_PAYOUTS, &pending);
+
+    // Escrow the total out of remaining_balance now, so a second proposal
+    // can't double-spend funds that are already committed to this one.
+    balance.remaining_balance -= total_payout;
+    program_data.balances.set(proposal.token.clone(), balance);
+    env.storage().instance().set(&PROGRAM_DATA, &program_data);
+
+    proposal.executed = true;
+    proposals.set(proposal_id, proposal.clone());
+    env.storage().persistent().set(&PAYOUT_PROPOSALS, &proposals);
+
+    env.events().publish(
+        (PAYOUT_QUEUED,),
+        (
+            program_data.program_id.clone(),
+            proposal_id,
+            proposal.recipients.len() as u32,
+            total_payout,
+            release_after,
+        ),
+    );
+
+    Ok(())
+}
+
 // ============================================================================
 // Contract Implementation
 // ============================================================================
@@ -466,46 +1289,66 @@ impl ProgramEscrowContract {
     /// # Arguments
     /// * `env` - The contract environment
     /// * `program_id` - Unique identifier for this program/hackathon
-    /// * `authorized_payout_key` - Address authorized to trigger payouts (backend)
-    /// * `token_address` - Address of the token contract for transfers (e.g., USDC)
-    ///
-    /// # Returns
-    /// * `ProgramData` - The initialized program configuration
+    /// * `authorized_payout_key` - Address authorized to manage program metadata
+    /// * `signers` - Addresses authorized to propose/approve payouts
+    /// * `threshold` - Distinct signer approvals required to execute a payout;
+    ///   must satisfy `1 <= threshold <= signers.len()`
+    /// * `proposal_window_seconds` - How long a proposal remains
+    ///   approvable/executable after `propose_payout` creates it
+    /// * `fee_bps` - Protocol fee in basis points skimmed to `treasury` on
+    ///   every `claim_payout`; `0` disables fees entirely. Must be `<=
+    ///   10_000` (100%).
+    /// * `treasury` - Address that receives the fee portion of each claimed
+    ///   payout; ignored (but still required) when `fee_bps` is `0`
     ///
     /// # Returns
     /// * `Ok(ProgramData)` - The initialized program configuration
     /// * `Err(Error::AlreadyInitialized)` - Program already initialized
+    /// * `Err(Error::InvalidThreshold)` - `threshold` is zero or exceeds `signers.len()`
+    /// * `Err(Error::InvalidFeeBps)` - `fee_bps` exceeds 10,000
     ///
     /// # State Changes
-    /// - Creates ProgramData with zero balances
-    /// - Sets authorized payout key (immutable after this)
+    /// - Creates ProgramData with an empty `balances` map; no token is
+    ///   funded until `lock_program_funds` is called for it
+    /// - Sets authorized payout key (rotatable later), signer set and
+    ///   threshold (signer set and threshold immutable after this)
+    /// - Initializes `last_key_rotation` to `0`
     /// - Initializes empty payout history
+    /// - Initializes `chain_head` to all-zero bytes (the hashchain genesis)
+    /// - Sets `fee_bps`/`treasury` and initializes `total_fees_collected` to `0`
     /// - Emits ProgramInitialized event
     ///
     /// # Security Considerations
     /// - Can only be called once (prevents re-configuration)
     /// - No authorization required (first-caller initialization)
-    /// - Authorized payout key should be a secure backend service
-    /// - Token address must be a valid Stellar Asset Contract
+    /// - Payouts require `threshold` distinct signer approvals; no single
+    ///   signer can trigger a payout alone
     /// - Program ID should be unique and descriptive
     ///
     /// # Events
-    /// Emits: `ProgramInit(program_id, authorized_payout_key, token_address, 0)`
+    /// Emits: `ProgramInit(program_id, authorized_payout_key, 0)`
     ///
     /// # Example
     /// ```rust
-    /// use soroban_sdk::{Address, String, Env};
-    /// 
+    /// use soroban_sdk::{Address, String, Env, vec};
+    ///
     /// let program_id = String::from_str(&env, "ETHGlobal2024");
     /// let backend = Address::from_string("GBACKEND...");
-    /// let usdc = Address::from_string("CUSDC...");
-    /// 
+    /// let signers = vec![&env, signer_a, signer_b, signer_c];
+    ///
     /// let program = escrow_client.init_program(
     ///     &program_id,
     ///     &backend,
-    ///     &usdc
+    ///     &signers,
+    ///     &2u32,
+    ///     &86_400u64,
+    ///     &250u32, // 2.5% protocol fee
+    ///     &treasury_address,
     /// );
-    /// 
+    ///
+    /// let usdc = Address::from_string("CUSDC...");
+    /// escrow_client.lock_program_funds(&backend, &usdc, &10_000_0000000);
+    ///
     /// println!("Program created: {}", program.program_id);
     /// ```
     ///
@@ -523,7 +1366,11 @@ impl ProgramEscrowContract {
     ///   -- init_program \
     ///   --program_id "Hackathon2024" \
     ///   --authorized_payout_key GBACKEND... \
-    ///   --token_address CUSDC...
+    ///   --signers '["GSIGNER1...", "GSIGNER2...", "GSIGNER3..."]' \
+    ///   --threshold 2 \
+    ///   --proposal_window_seconds 86400 \
+    ///   --fee_bps 250 \
+    ///   --treasury GTREASURY...
     /// ```
     ///
     /// # Gas Cost
@@ -532,21 +1379,45 @@ impl ProgramEscrowContract {
         env: Env,
         program_id: String,
         authorized_payout_key: Address,
-        token_address: Address,
+        signers: Vec<Address>,
+        threshold: u32,
+        proposal_window_seconds: u64,
+        fee_bps: u32,
+        treasury: Address,
     ) -> Result<ProgramData, Error> {
         // Prevent re-initialization
         if env.storage().instance().has(&PROGRAM_DATA) {
             return Err(Error::AlreadyInitialized);
         }
 
-        // Create program data with zero balances
+        if threshold == 0 || threshold > signers.len() {
+            return Err(Error::InvalidThreshold);
+        }
+
+        if (fee_bps as i128) > FEE_BPS_DENOMINATOR {
+            return Err(Error::InvalidFeeBps);
+        }
+
+        // Create program data with no tokens funded yet
         let program_data = ProgramData {
             program_id: program_id.clone(),
-            total_funds: 0,
-            remaining_balance: 0,
+            balances: Map::new(&env),
             authorized_payout_key: authorized_payout_key.clone(),
-            payout_history: vec![&env],
-            token_address: token_address.clone(),
+            last_key_rotation: 0,
+            payout_count: 0,
+            chain_head: BytesN::from_array(&env, &[0u8; 32]),
+            signers,
+            threshold,
+            proposal_window_seconds,
+            next_proposal_id: 0,
+            next_pending_payout_id: 0,
+            fee_bps,
+            treasury,
+            total_fees_collected: 0,
+            max_single_payout: None,
+            max_batch_total: None,
+            max_batch_recipients: None,
+            payout_nonce: 0,
         };
 
         // Store program configuration
@@ -555,7 +1426,7 @@ impl ProgramEscrowContract {
         // Emit initialization event
         env.events().publish(
             (PROGRAM_INITIALIZED,),
-            (program_id, authorized_payout_key, token_address, 0i128),
+            (program_id, authorized_payout_key, 0i128),
         );
 
         Ok(program_data)
@@ -628,433 +1499,1462 @@ impl ProgramEscrowContract {
     }
 
     // ========================================================================
-    // Fund Management
+    // Key Management
     // ========================================================================
 
-    /// Locks funds into the program escrow for prize distribution.
+    /// Rotates the authorized payout key to a new address.
+    ///
+    /// If the current key is lost or has already been revoked (so it can
+    /// no longer authorize this call itself), use `propose_key_rotation`/
+    /// `approve_key_rotation` instead - the signer quorum can rotate or
+    /// revoke the key without it.
     ///
     /// # Arguments
     /// * `env` - The contract environment
-    /// * `amount` - Amount of tokens to lock (in token's smallest denomination)
+    /// * `new_key` - Address to become the new authorized payout key
     ///
     /// # Returns
-    /// * `ProgramData` - Updated program data with new balance
-    ///
-    /// # Returns
-    /// * `Ok(ProgramData)` - Updated program data with new balance
-    /// * `Err(Error::InvalidAmount)` - Amount must be greater than zero
+    /// * `Ok(())` - Key successfully rotated
     /// * `Err(Error::NotInitialized)` - Program not initialized
+    /// * `Err(Error::RotationOnCooldown)` - Called before
+    ///   `ROTATION_COOLDOWN_SECONDS` have elapsed since the last rotation
+    ///   or revocation
     ///
     /// # State Changes
-    /// - Increases `total_funds` by amount
-    /// - Increases `remaining_balance` by amount
-    /// - Emits FundsLocked event
+    /// - Updates `authorized_payout_key` to `new_key`
+    /// - Updates `last_key_rotation` to the current ledger timestamp
+    /// - Emits KeyRotated event
     ///
-    /// # Prerequisites
-    /// Before calling this function:
-    /// 1. Caller must have sufficient token balance
-    /// 2. Caller must approve contract for token transfer
-    /// 3. Tokens must actually be transferred to contract
+    /// # Authorization
+    /// - Requires `require_auth()` from the *current* `authorized_payout_key`
     ///
     /// # Security Considerations
-    /// - Amount must be positive
-    /// - This function doesn't perform the actual token transfer
-    /// - Caller is responsible for transferring tokens to contract
-    /// - Consider verifying contract balance matches recorded amount
-    /// - Multiple lock operations are additive (cumulative)
+    /// - The cooldown bounds how often the key can rotate, so a leaked key
+    ///   can't repeatedly hop to attacker-controlled addresses without
+    ///   leaving an auditable `KeyRotated` event trail for organizers to
+    ///   react to
+    /// - If the key is believed compromised rather than merely due for
+    ///   routine rotation, prefer `revoke_payout_key`, which is immediate
     ///
     /// # Events
-    /// Emits: `FundsLocked(program_id, amount, new_remaining_balance)`
+    /// Emits: `KeyRotated(program_id, old_key, new_key)`
     ///
     /// # Example
     /// ```rust
-    /// use soroban_sdk::token;
-    /// 
-    /// // 1. Transfer tokens to contract
-    /// let amount = 10_000_0000000; // 10,000 USDC
-    /// token_client.transfer(
-    ///     &organizer,
-    ///     &contract_address,
-    ///     &amount
-    /// );
-    /// 
-    /// // 2. Record the locked funds
-    /// let updated = escrow_client.lock_program_funds(&amount);
-    /// println!("Locked: {} USDC", amount / 10_000_000);
-    /// println!("Remaining: {}", updated.remaining_balance);
-    /// ```
-    ///
-    /// # Production Usage
-    /// ```bash
-    /// # 1. Transfer USDC to contract
-    /// stellar contract invoke \
-    ///   --id USDC_TOKEN_ID \
-    ///   --source ORGANIZER_KEY \
-    ///   -- transfer \
-    ///   --from ORGANIZER_ADDRESS \
-    ///   --to CONTRACT_ADDRESS \
-    ///   --amount 10000000000
-    ///
-    /// # 2. Record locked funds
-    /// stellar contract invoke \
-    ///   --id CONTRACT_ID \
-    ///   --source ORGANIZER_KEY \
-    ///   -- lock_program_funds \
-    ///   --amount 10000000000
+    /// escrow_client.rotate_payout_key(&new_backend_address);
     /// ```
-    ///
-    /// # Gas Cost
-    /// Low - Storage update + event emission
-    ///
-    /// # Common Pitfalls
-    /// - Forgetting to transfer tokens before calling
-    /// -  Locking amount that exceeds actual contract balance
-    /// -  Not verifying contract received the tokens
-    pub fn lock_program_funds(env: Env, amount: i128) -> Result<ProgramData, Error> {
-        // Validate amount
-        if amount <= 0 {
-            return Err(Error::InvalidAmount);
-        }
-
-        // Verify program is initialized
+    pub fn rotate_payout_key(env: Env, new_key: Address) -> Result<(), Error> {
         if !env.storage().instance().has(&PROGRAM_DATA) {
             return Err(Error::NotInitialized);
         }
 
-        // Get current program data
         let mut program_data: ProgramData = env
             .storage()
             .instance()
             .get(&PROGRAM_DATA)
             .unwrap();
 
-        // Update balances (cumulative)
-        program_data.total_funds += amount;
-        program_data.remaining_balance += amount;
+        program_data.authorized_payout_key.require_auth();
+
+        let now = env.ledger().timestamp();
+        if now < program_data.last_key_rotation + ROTATION_COOLDOWN_SECONDS {
+            return Err(Error::RotationOnCooldown);
+        }
+
+        let old_key = program_data.authorized_payout_key.clone();
+        program_data.authorized_payout_key = new_key.clone();
+        program_data.last_key_rotation = now;
 
-        // Store updated data
         env.storage().instance().set(&PROGRAM_DATA, &program_data);
 
-        // Emit funds locked event
         env.events().publish(
-            (FUNDS_LOCKED,),
-            (
-                program_data.program_id.clone(),
-                amount,
-                program_data.remaining_balance,
-            ),
+            (KEY_ROTATED,),
+            (program_data.program_id.clone(), old_key, new_key),
         );
 
-        Ok(program_data)
+        Ok(())
     }
 
-    // ========================================================================
-    // Payout Functions
-    // ========================================================================
-
-    /// Executes batch payouts to multiple recipients simultaneously.
+    /// Immediately revokes the authorized payout key, freezing the
+    /// single-key administrative surface until a new key is installed via
+    /// `rotate_payout_key` (or, since the key that would normally call that
+    /// is exactly what's now frozen, via the signer quorum's
+    /// `propose_key_rotation`/`approve_key_rotation`).
     ///
     /// # Arguments
     /// * `env` - The contract environment
-    /// * `recipients` - Vector of recipient addresses
-    /// * `amounts` - Vector of amounts (must match recipients length)
     ///
     /// # Returns
-    /// * `Ok(ProgramData)` - Updated program data after payouts
-    /// * `Err(Error::Unauthorized)` - Caller is not the authorized payout key
+    /// * `Ok(())` - Key successfully revoked
     /// * `Err(Error::NotInitialized)` - Program not initialized
-    /// * `Err(Error::BatchMismatch)` - Recipients and amounts vectors length mismatch
-    /// * `Err(Error::InvalidAmount)` - Amount is zero or negative
-    /// * `Err(Error::InsufficientBalance)` - Total payout exceeds remaining balance
-    ///
-    /// # Authorization
-    /// - **CRITICAL**: Only authorized payout key can call
-    /// - Caller must be exact match to `authorized_payout_key`
     ///
     /// # State Changes
-    /// - Transfers tokens from contract to each recipient
-    /// - Adds PayoutRecord for each transfer to history
-    /// - Decreases `remaining_balance` by total payout amount
-    /// - Emits BatchPayout event
+    /// - Sets `authorized_payout_key` to a sentinel address
+    ///   (`env.current_contract_address()`) that no external caller can
+    ///   ever satisfy `require_auth()` for
+    /// - Updates `last_key_rotation` to the current ledger timestamp
+    /// - Emits KeyRotated event
     ///
-    /// # Atomicity
-    /// This operation is atomic - either all transfers succeed or all fail.
-    /// If any transfer fails, the entire batch is reverted.
+    /// # Authorization
+    /// - Requires `require_auth()` from the *current* `authorized_payout_key`
+    /// - Bypasses the rotation cooldown: an emergency freeze must take
+    ///   effect immediately, not wait out an attacker's window
     ///
     /// # Security Considerations
-    /// - Verify recipient addresses off-chain before calling
-    /// - Ensure amounts match winner rankings/criteria
-    /// - Total payout is calculated with overflow protection
-    /// - Balance check prevents overdraft
-    /// - All transfers are logged for audit trail
-    /// - Consider implementing payout limits for additional safety
+    /// - Once revoked, `authorized_payout_key` can never satisfy
+    ///   `require_auth()` again on its own, so `set_program_metadata` and
+    ///   further `rotate_payout_key` calls are blocked until the signer
+    ///   quorum installs a new key via `propose_key_rotation`/
+    ///   `approve_key_rotation`
+    /// - The quorum payout path (`propose_payout`/`approve_payout`, then
+    ///   `claim_payout` or `claim_vested` by the recipient) is gated by the
+    ///   signer/threshold quorum, not `authorized_payout_key`, and keeps
+    ///   working after revocation; the key-gated entrypoints
+    ///   (`batch_payout_idempotent`, `dispute_payout`,
+    ///   `schedule_vested_payout`, `set_payout_limits`) are frozen with it
     ///
     /// # Events
-    /// Emits: `BatchPayout(program_id, recipient_count, total_amount, new_balance)`
+    /// Emits: `KeyRotated(program_id, old_key, sentinel_key)`
     ///
     /// # Example
     /// ```rust
-    /// use soroban_sdk::{vec, Address};
-    /// 
-    /// // Define winners and prizes
-    /// let winners = vec![
-    ///     &env,
-    ///     Address::from_string("GWINNER1..."), // 1st place
-    ///     Address::from_string("GWINNER2..."), // 2nd place
-    ///     Address::from_string("GWINNER3..."), // 3rd place
-    /// ];
-    /// 
-    /// let prizes = vec![
-    ///     &env,
-    ///     5_000_0000000,  // $5,000 USDC
-    ///     3_000_0000000,  // $3,000 USDC
-    ///     2_000_0000000,  // $2,000 USDC
-    /// ];
-    /// 
-    /// // Execute batch payout (only authorized backend can call)
-    /// let result = escrow_client.batch_payout(&winners, &prizes);
-    /// println!("Paid {} winners", winners.len());
-    /// println!("Remaining: {}", result.remaining_balance);
+    /// // Suspected key leak - freeze the admin key immediately
+    /// escrow_client.revoke_payout_key();
     /// ```
-    ///
-    /// # Production Usage
-    /// ```bash
-    /// # Batch payout to 3 winners
-    /// stellar contract invoke \
-    ///   --id CONTRACT_ID \
-    ///   --source BACKEND_KEY \
-    ///   -- batch_payout \
-    ///   --recipients '["GWINNER1...", "GWINNER2...", "GWINNER3..."]' \
-    ///   --amounts '[5000000000, 3000000000, 2000000000]'
-    /// ```
-    ///
-    /// # Gas Cost
-    /// High - Multiple token transfers + storage updates
-    /// Cost scales linearly with number of recipients
-    ///
-    /// # Best Practices
-    /// 1. Verify all winner addresses before execution
-    /// 2. Double-check prize amounts match criteria
-    /// 3. Test on testnet with same number of recipients
-    /// 4. Monitor events for successful completion
-    /// 5. Keep batch size reasonable (recommend < 50 recipients)
-    ///
-    /// # Limitations
-    /// - Maximum batch size limited by gas/resource limits
-    /// - For very large batches, consider multiple calls
-    /// - All amounts must be positive
-    pub fn batch_payout(
+    pub fn revoke_payout_key(env: Env) -> Result<(), Error> {
+        if !env.storage().instance().has(&PROGRAM_DATA) {
+            return Err(Error::NotInitialized);
+        }
+
+        let mut program_data: ProgramData = env
+            .storage()
+            .instance()
+            .get(&PROGRAM_DATA)
+            .unwrap();
+
+        program_data.authorized_payout_key.require_auth();
+
+        let old_key = program_data.authorized_payout_key.clone();
+        let sentinel = env.current_contract_address();
+        program_data.authorized_payout_key = sentinel.clone();
+        program_data.last_key_rotation = env.ledger().timestamp();
+
+        env.storage().instance().set(&PROGRAM_DATA, &program_data);
+
+        env.events().publish(
+            (KEY_ROTATED,),
+            (program_data.program_id.clone(), old_key, sentinel),
+        );
+
+        Ok(())
+    }
+
+    /// Proposes rotating or revoking `authorized_payout_key` via the signer
+    /// quorum, as a recovery path for when the key itself is lost, leaked,
+    /// or already revoked and so can't call `rotate_payout_key` itself.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `proposer` - Must be a registered signer (must authorize)
+    /// * `new_key` - `Some(address)` to rotate to that key once approved,
+    ///   or `None` to revoke (mirrors `revoke_payout_key`)
+    ///
+    /// # Returns
+    /// * `Ok(proposal_id)` - Id to pass to `approve_key_rotation`
+    /// * `Err(Error::NotInitialized)` - Program not initialized
+    /// * `Err(Error::Unauthorized)` - `proposer` is not a registered signer
+    ///
+    /// # State Changes
+    /// - Stores a new `KeyRotationProposal` with no approvals yet
+    ///
+    /// # Example
+    /// ```rust
+    /// // Key was revoked after a suspected leak; recover via quorum.
+    /// let proposal_id = escrow_client.propose_key_rotation(&signer1, &Some(new_backend));
+    /// ```
+    pub fn propose_key_rotation(
         env: Env,
-        recipients: Vec<Address>,
-        amounts: Vec<i128>,
+        proposer: Address,
+        new_key: Option<Address>,
+    ) -> Result<u64, Error> {
+        proposer.require_auth();
+
+        if !env.storage().instance().has(&PROGRAM_DATA) {
+            return Err(Error::NotInitialized);
+        }
+        let program_data: ProgramData = env.storage().instance().get(&PROGRAM_DATA).unwrap();
+
+        if !program_data.signers.contains(&proposer) {
+            return Err(Error::Unauthorized);
+        }
+
+        let proposal_id: u64 = env
+            .storage()
+            .instance()
+            .get(&NEXT_KEY_ROTATION_PROPOSAL_ID)
+            .unwrap_or(0);
+        env.storage()
+            .instance()
+            .set(&NEXT_KEY_ROTATION_PROPOSAL_ID, &(proposal_id + 1));
+
+        let proposal = KeyRotationProposal {
+            id: proposal_id,
+            new_key,
+            approvals: vec![&env],
+            created_at: env.ledger().timestamp(),
+            executed: false,
+        };
+
+        let mut proposals = load_key_rotation_proposals(&env);
+        proposals.set(proposal_id, proposal);
+        env.storage()
+            .persistent()
+            .set(&KEY_ROTATION_PROPOSALS, &proposals);
+
+        Ok(proposal_id)
+    }
+
+    /// Casts one signer's approval toward a pending key rotation proposal,
+    /// executing it once `threshold` distinct approvals have accumulated.
+    ///
+    /// Unlike `rotate_payout_key`, this bypasses `ROTATION_COOLDOWN_SECONDS`:
+    /// it exists specifically to recover when the single-key path is
+    /// unavailable, so it must take effect as soon as quorum is reached.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `proposal_id` - Id returned by `propose_key_rotation`
+    /// * `approver` - Signer casting this approval (must authorize)
+    ///
+    /// # Returns
+    /// * `Ok(true)` - This approval reached `threshold`; the key was rotated
+    /// * `Ok(false)` - Approval recorded, but `threshold` isn't reached yet
+    /// * `Err(Error::NotInitialized)` - Program not initialized
+    /// * `Err(Error::Unauthorized)` - `approver` is not a registered signer
+    /// * `Err(Error::ProposalNotFound)` - `proposal_id` doesn't exist
+    /// * `Err(Error::ProposalAlreadyExecuted)` - Key already rotated from this proposal
+    /// * `Err(Error::AlreadyApproved)` - `approver` already approved this proposal
+    ///
+    /// # Events
+    /// Emits: `KeyRotated(program_id, old_key, new_key)` once `threshold` is reached
+    pub fn approve_key_rotation(
+        env: Env,
+        proposal_id: u64,
+        approver: Address,
+    ) -> Result<bool, Error> {
+        approver.require_auth();
+
+        if !env.storage().instance().has(&PROGRAM_DATA) {
+            return Err(Error::NotInitialized);
+        }
+        let mut program_data: ProgramData = env.storage().instance().get(&PROGRAM_DATA).unwrap();
+        if !program_data.signers.contains(&approver) {
+            return Err(Error::Unauthorized);
+        }
+
+        let mut proposals = load_key_rotation_proposals(&env);
+        let mut proposal = proposals.get(proposal_id).ok_or(Error::ProposalNotFound)?;
+
+        if proposal.executed {
+            return Err(Error::ProposalAlreadyExecuted);
+        }
+        if proposal.approvals.contains(&approver) {
+            return Err(Error::AlreadyApproved);
+        }
+
+        proposal.approvals.push_back(approver.clone());
+        let reached_threshold = proposal.approvals.len() >= program_data.threshold;
+
+        if reached_threshold {
+            proposal.executed = true;
+
+            let old_key = program_data.authorized_payout_key.clone();
+            let new_key = proposal
+                .new_key
+                .clone()
+                .unwrap_or_else(|| env.current_contract_address());
+            program_data.authorized_payout_key = new_key.clone();
+            program_data.last_key_rotation = env.ledger().timestamp();
+            env.storage().instance().set(&PROGRAM_DATA, &program_data);
+
+            env.events().publish(
+                (KEY_ROTATED,),
+                (program_data.program_id.clone(), old_key, new_key),
+            );
+        }
+
+        proposals.set(proposal_id, proposal);
+        env.storage()
+            .persistent()
+            .set(&KEY_ROTATION_PROPOSALS, &proposals);
+
+        Ok(reached_threshold)
+    }
+
+    // ========================================================================
+    // Risk Limits
+    // ========================================================================
+
+    /// Sets or clears the organizer's risk-limit envelope for proposals and
+    /// idempotent batches, independent of the remaining-balance check.
+    ///
+    /// A compromised `authorized_payout_key` can still call
+    /// `batch_payout_idempotent` directly; these limits bound how much
+    /// damage a single call can do regardless of how much remains locked.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `max_single_payout` - Cap on any one recipient's amount within a
+    ///   proposal or batch; `None` disables the check
+    /// * `max_batch_total` - Cap on a proposal's or batch's combined total;
+    ///   `None` disables the check
+    /// * `max_batch_recipients` - Cap on the number of recipients in a
+    ///   single proposal or batch; `None` disables the check
+    ///
+    /// # Returns
+    /// * `Ok(())` - Limits updated
+    /// * `Err(Error::NotInitialized)` - Program not initialized
+    ///
+    /// # State Changes
+    /// - Overwrites `max_single_payout`/`max_batch_total`/`max_batch_recipients`
+    ///
+    /// # Authorization
+    /// Requires `require_auth()` from the current `authorized_payout_key`
+    /// (the organizer).
+    ///
+    /// # Example
+    /// ```rust
+    /// // No single recipient above 10,000 USDC, no batch above 50,000 USDC
+    /// // total, and no more than 20 recipients per batch
+    /// escrow_client.set_payout_limits(
+    ///     &Some(10_000_0000000),
+    ///     &Some(50_000_0000000),
+    ///     &Some(20u32),
+    /// );
+    /// ```
+    pub fn set_payout_limits(
+        env: Env,
+        max_single_payout: Option<i128>,
+        max_batch_total: Option<i128>,
+        max_batch_recipients: Option<u32>,
+    ) -> Result<(), Error> {
+        if !env.storage().instance().has(&PROGRAM_DATA) {
+            return Err(Error::NotInitialized);
+        }
+
+        let mut program_data: ProgramData = env.storage().instance().get(&PROGRAM_DATA).unwrap();
+        program_data.authorized_payout_key.require_auth();
+
+        program_data.max_single_payout = max_single_payout;
+        program_data.max_batch_total = max_batch_total;
+        program_data.max_batch_recipients = max_batch_recipients;
+
+        env.storage().instance().set(&PROGRAM_DATA, &program_data);
+
+        Ok(())
+    }
+
+    // ========================================================================
+    // Fund Management
+    // ========================================================================
+
+    /// Locks funds of a given token into the program escrow for prize
+    /// distribution.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `token` - Address of the token contract being funded (e.g., USDC,
+    ///   XLM, or a project's own asset); a program may fund any number of
+    ///   distinct tokens, each tracked independently
+    /// * `funder` - Address the tokens are pulled from; must authorize this
+    ///   call and must have approved the contract to spend at least `amount`
+    /// * `amount` - Amount of tokens to lock (in token's smallest denomination)
+    ///
+    /// # Returns
+    /// * `Ok(ProgramData)` - Updated program data with new balance
+    /// * `Err(Error::InvalidAmount)` - Amount must be greater than zero
+    /// * `Err(Error::NotInitialized)` - Program not initialized
+    ///
+    /// # State Changes
+    /// - Pulls `amount` of `token` from `funder` into the contract via
+    ///   `transfer_from`, atomically with the accounting update below
+    /// - Creates a `TokenBalance` entry for `token` in `balances` on first
+    ///   use, or updates the existing one
+    /// - Increases that entry's `total_funds` by amount
+    /// - Increases that entry's `remaining_balance` by amount
+    /// - Emits FundsLocked event
+    ///
+    /// # Prerequisites
+    /// Before calling this function, `funder` must have called the token
+    /// contract's `approve` to grant this contract an allowance of at least
+    /// `amount`.
+    ///
+    /// # Security Considerations
+    /// - Amount must be positive
+    /// - Requires `funder.require_auth()`; the transfer and the recorded
+    ///   balance move in the same call, so `total_funds`/`remaining_balance`
+    ///   can never drift from the contract's actual token balance the way a
+    ///   separate "transfer, then record" flow could
+    /// - Multiple lock operations are additive (cumulative)
+    ///
+    /// # Events
+    /// Emits: `FundsLocked(program_id, token, amount, new_remaining_balance)`
+    ///
+    /// # Example
+    /// ```rust
+    /// // 1. Organizer approves the contract to pull the prize pool
+    /// token_client.approve(&organizer, &contract_address, &amount, &expiration_ledger);
+    ///
+    /// // 2. Locking pulls the tokens and records the balance atomically
+    /// let updated = escrow_client.lock_program_funds(&organizer, &usdc, &amount);
+    /// println!("Locked: {} USDC", amount / 10_000_000);
+    /// ```
+    ///
+    /// # Production Usage
+    /// ```bash
+    /// # 1. Approve the contract to spend on the organizer's behalf
+    /// stellar contract invoke \
+    ///   --id USDC_TOKEN_ID \
+    ///   --source ORGANIZER_KEY \
+    ///   -- approve \
+    ///   --from ORGANIZER_ADDRESS \
+    ///   --spender CONTRACT_ADDRESS \
+    ///   --amount 10000000000 \
+    ///   --expiration_ledger 1000000
+    ///
+    /// # 2. Lock funds; the contract pulls them itself
+    /// stellar contract invoke \
+    ///   --id CONTRACT_ID \
+    ///   --source ORGANIZER_KEY \
+    ///   -- lock_program_funds \
+    ///   --funder ORGANIZER_ADDRESS \
+    ///   --token USDC_TOKEN_ID \
+    ///   --amount 10000000000
+    /// ```
+    ///
+    /// # Gas Cost
+    /// Low - One token transfer + storage update + event emission
+    ///
+    /// # Common Pitfalls
+    /// - Forgetting to `approve` the contract before calling (the transfer fails)
+    /// - Approving a smaller amount than intended to lock
+    pub fn lock_program_funds(
+        env: Env,
+        funder: Address,
+        token: Address,
+        amount: i128,
     ) -> Result<ProgramData, Error> {
+        funder.require_auth();
+
+        // Validate amount
+        if amount <= 0 {
+            return Err(Error::InvalidAmount);
+        }
+
         // Verify program is initialized
         if !env.storage().instance().has(&PROGRAM_DATA) {
             return Err(Error::NotInitialized);
         }
 
         // Get current program data
-        let program_data: ProgramData = env
+        let mut program_data: ProgramData = env
             .storage()
             .instance()
             .get(&PROGRAM_DATA)
             .unwrap();
 
-        // Verify authorization - CRITICAL security check
-        let caller = env.invoker();
-        if caller != program_data.authorized_payout_key {
-            return Err(Error::Unauthorized);
+        // Pull the tokens in before recording anything, so a failed
+        // transfer (e.g. insufficient allowance) never leaves the
+        // accounting out of sync with the contract's actual balance.
+        let contract_address = env.current_contract_address();
+        let token_client = token::Client::new(&env, &token);
+        token_client.transfer_from(&contract_address, &funder, &contract_address, &amount);
+
+        // Get or create this token's balance entry, then update it
+        // (cumulative across repeated locks of the same token).
+        let mut balance = program_data
+            .balances
+            .get(token.clone())
+            .unwrap_or(TokenBalance {
+                total_funds: 0,
+                remaining_balance: 0,
+            });
+        balance.total_funds += amount;
+        balance.remaining_balance += amount;
+        program_data.balances.set(token.clone(), balance.clone());
+
+        // Store updated data
+        env.storage().instance().set(&PROGRAM_DATA, &program_data);
+
+        // Emit funds locked event
+        env.events().publish(
+            (FUNDS_LOCKED,),
+            (
+                program_data.program_id.clone(),
+                token,
+                amount,
+                balance.remaining_balance,
+            ),
+        );
+
+        Ok(program_data)
+    }
+
+    // ========================================================================
+    // Payout Functions
+    // ========================================================================
+
+    /// Proposes a batch of payouts for the signer quorum to approve.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `proposer` - Signer creating the proposal (must authorize)
+    /// * `token` - Token the payout will be made in; must already have been
+    ///   funded via `lock_program_funds`
+    /// * `recipients` - Vector of recipient addresses
+    /// * `amounts` - Vector of amounts (must match recipients length); a
+    ///   single-recipient payout is just a length-1 batch
+    ///
+    /// # Returns
+    /// * `Ok(u64)` - The new proposal's id, used with `approve_payout`/`execute_payout`
+    /// * `Err(Error::NotInitialized)` - Program not initialized
+    /// * `Err(Error::Unauthorized)` - `proposer` is not a registered signer
+    /// * `Err(Error::TokenNotFunded)` - `token` has never been locked via `lock_program_funds`
+    /// * `Err(Error::BatchMismatch)` - Recipients and amounts vectors length mismatch (or empty)
+    /// * `Err(Error::InvalidAmount)` - Amount is zero or negative
+    /// * `Err(Error::InsufficientBalance)` - Total payout exceeds the token's remaining balance
+    /// * `Err(Error::PayoutLimitExceeded)` - Recipients, an individual amount, or the
+    ///   total exceeds a limit set via `set_payout_limits`
+    ///
+    /// # Authorization
+    /// `proposer` must be part of `ProgramData.signers` and must authorize
+    /// this call. Proposing does not move funds by itself.
+    ///
+    /// # State Changes
+    /// - Stores a `PayoutProposal` with an empty approval set
+    /// - Sets `expires_at = now + proposal_window_seconds`
+    /// - Advances `next_proposal_id`
+    /// - Emits `PropPaid` event
+    ///
+    /// # Security Considerations
+    /// - The balance check here is a sanity check, not a guarantee: the
+    ///   balance is re-validated at execution time since other proposals may
+    ///   execute first
+    /// - Verify recipient addresses and amounts off-chain before proposing
+    pub fn propose_payout(
+        env: Env,
+        proposer: Address,
+        token: Address,
+        recipients: Vec<Address>,
+        amounts: Vec<i128>,
+    ) -> Result<u64, Error> {
+        proposer.require_auth();
+
+        if !env.storage().instance().has(&PROGRAM_DATA) {
+            return Err(Error::NotInitialized);
         }
+        let mut program_data: ProgramData = env.storage().instance().get(&PROGRAM_DATA).unwrap();
 
-        // Validate input lengths match
-        if recipients.len() != amounts.len() {
-            return Err(Error::BatchMismatch);
+        if !program_data.signers.contains(&proposer) {
+            return Err(Error::Unauthorized);
         }
 
-        // Validate non-empty batch
-        if recipients.len() == 0 {
+        let balance = program_data
+            .balances
+            .get(token.clone())
+            .ok_or(Error::TokenNotFunded)?;
+
+        if recipients.len() != amounts.len() || recipients.len() == 0 {
             return Err(Error::BatchMismatch);
         }
+        if let Some(max_recipients) = program_data.max_batch_recipients {
+            if recipients.len() > max_recipients {
+                return Err(Error::PayoutLimitExceeded);
+            }
+        }
 
-        // Calculate total payout with overflow protection
         let mut total_payout: i128 = 0;
         for amount in amounts.iter() {
-            if *amount <= 0 {
+            if amount <= 0 {
                 return Err(Error::InvalidAmount);
             }
-            total_payout = total_payout
-                .checked_add(*amount)
-                .ok_or(Error::InvalidAmount)?;
+            if let Some(max_single) = program_data.max_single_payout {
+                if amount > max_single {
+                    return Err(Error::PayoutLimitExceeded);
+                }
+            }
+            total_payout = total_payout.checked_add(amount).ok_or(Error::InvalidAmount)?;
         }
-
-        // Validate sufficient balance
-        if total_payout > program_data.remaining_balance {
+        if total_payout > balance.remaining_balance {
             return Err(Error::InsufficientBalance);
         }
+        if let Some(max_total) = program_data.max_batch_total {
+            if total_payout > max_total {
+                return Err(Error::PayoutLimitExceeded);
+            }
+        }
 
-        // Execute transfers and record payouts
-        let mut updated_history = program_data.payout_history.clone();
-        let timestamp = env.ledger().timestamp();
-        let contract_address = env.current_contract_address();
-        let token_client = token::Client::new(&env, &program_data.token_address);
+        let proposal_id = program_data.next_proposal_id;
+        program_data.next_proposal_id += 1;
+
+        let now = env.ledger().timestamp();
+        let proposal = PayoutProposal {
+            id: proposal_id,
+            token,
+            recipients: recipients.clone(),
+            amounts,
+            approvals: vec![&env],
+            created_at: now,
+            expires_at: now + program_data.proposal_window_seconds,
+            executed: false,
+        };
 
-        for (i, recipient) in recipients.iter().enumerate() {
-            let amount = amounts.get(i).unwrap();
+        let mut proposals = load_proposals(&env);
+        proposals.set(proposal_id, proposal);
+        env.storage().persistent().set(&PAYOUT_PROPOSALS, &proposals);
+        env.storage().instance().set(&PROGRAM_DATA, &program_data);
+
+        env.events().publish(
+            (PAYOUT_PROPOSED,),
+            (program_data.program_id, proposal_id, recipients.len() as u32, total_payout),
+        );
 
-            // Transfer tokens from contract to recipient
-            token_client.transfer(&contract_address, recipient, amount);
+        Ok(proposal_id)
+    }
 
-            // Record payout in history
-            let payout_record = PayoutRecord {
-                recipient: recipient.clone(),
-                amount: *amount,
-                timestamp,
-            };
-            updated_history.push_back(payout_record);
+    /// Casts one signer's approval toward a pending payout proposal,
+    /// executing it once `threshold` distinct approvals have accumulated.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `proposal_id` - Id returned by `propose_payout`
+    /// * `approver` - Signer casting this approval (must authorize)
+    ///
+    /// # Returns
+    /// * `Ok(true)` - This approval reached `threshold`; the payout executed
+    /// * `Ok(false)` - Approval recorded, but `threshold` isn't reached yet
+    /// * `Err(Error::NotInitialized)` - Program not initialized
+    /// * `Err(Error::Unauthorized)` - `approver` is not a registered signer
+    /// * `Err(Error::ProposalNotFound)` - `proposal_id` doesn't exist
+    /// * `Err(Error::ProposalAlreadyExecuted)` - Already paid out
+    /// * `Err(Error::ProposalExpired)` - `proposal_window_seconds` has elapsed
+    /// * `Err(Error::AlreadyApproved)` - `approver` already approved this proposal
+    ///
+    /// # State Changes
+    /// - Adds `approver` to the proposal's `approvals`
+    /// - Once `approvals.len() >= threshold`: escrows the amounts out of
+    ///   `remaining_balance` into one `PendingPayout` per recipient and marks
+    ///   the proposal `executed` (see `execute_proposal`); the transfers,
+    ///   hashchain extension and history append happen later in `claim_payout`
+    /// - Emits `PropAppr`, and `PayQueued` if this call queued the payout
+    pub fn approve_payout(env: Env, proposal_id: u64, approver: Address) -> Result<bool, Error> {
+        approver.require_auth();
+
+        if !env.storage().instance().has(&PROGRAM_DATA) {
+            return Err(Error::NotInitialized);
+        }
+        let program_data: ProgramData = env.storage().instance().get(&PROGRAM_DATA).unwrap();
+        if !program_data.signers.contains(&approver) {
+            return Err(Error::Unauthorized);
         }
 
-        // Update program data
-        let mut updated_data = program_data.clone();
-        updated_data.remaining_balance -= total_payout;
-        updated_data.payout_history = updated_history;
+        let mut proposals = load_proposals(&env);
+        let mut proposal = proposals.get(proposal_id).ok_or(Error::ProposalNotFound)?;
 
-        // Store updated data
-        env.storage()
-            .instance()
-            .set(&PROGRAM_DATA, &updated_data);
+        if proposal.executed {
+            return Err(Error::ProposalAlreadyExecuted);
+        }
+        if env.ledger().timestamp() > proposal.expires_at {
+            return Err(Error::ProposalExpired);
+        }
+        if proposal.approvals.contains(&approver) {
+            return Err(Error::AlreadyApproved);
+        }
+
+        proposal.approvals.push_back(approver.clone());
+        let reached_threshold = proposal.approvals.len() >= program_data.threshold;
+
+        proposals.set(proposal_id, proposal.clone());
+        env.storage().persistent().set(&PAYOUT_PROPOSALS, &proposals);
 
-        // Emit batch payout event
         env.events().publish(
-            (BATCH_PAYOUT,),
-            (
-                updated_data.program_id.clone(),
-                recipients.len() as u32,
-                total_payout,
-                updated_data.remaining_balance,
-            ),
+            (PAYOUT_APPROVED,),
+            (program_data.program_id, proposal_id, approver, proposal.approvals.len() as u32),
         );
 
-        Ok(updated_data)
+        if reached_threshold {
+            execute_proposal(&env, proposal_id)?;
+        }
+
+        Ok(reached_threshold)
     }
 
-    /// Executes a single payout to one recipient.
+    /// Explicitly executes a proposal that has already reached `threshold`
+    /// approvals, for callers who'd rather not rely on the last
+    /// `approve_payout` auto-executing (e.g. retrying after a prior
+    /// execution attempt failed for an unrelated reason).
     ///
     /// # Arguments
     /// * `env` - The contract environment
-    /// * `recipient` - Address of the prize recipient
-    /// * `amount` - Amount to transfer (in token's smallest denomination)
+    /// * `proposal_id` - Id returned by `propose_payout`
     ///
     /// # Returns
-    /// * `Ok(ProgramData)` - Updated program data after payout
-    /// * `Err(Error::Unauthorized)` - Caller is not the authorized payout key
+    /// * `Ok(ProgramData)` - Updated program data after the payout executed
     /// * `Err(Error::NotInitialized)` - Program not initialized
-    /// * `Err(Error::InvalidAmount)` - Amount is zero or negative
-    /// * `Err(Error::InsufficientBalance)` - Amount exceeds remaining balance
+    /// * `Err(Error::ProposalNotFound)` - `proposal_id` doesn't exist
+    /// * `Err(Error::ProposalAlreadyExecuted)` - Already paid out
+    /// * `Err(Error::ProposalExpired)` - `proposal_window_seconds` has elapsed
+    /// * `Err(Error::Unauthorized)` - Fewer than `threshold` approvals recorded
     ///
     /// # Authorization
-    /// - Only authorized payout key can call this function
+    /// Permissionless: quorum was already established by `approve_payout`,
+    /// so no further signature is required to release it.
+    pub fn execute_payout(env: Env, proposal_id: u64) -> Result<ProgramData, Error> {
+        if !env.storage().instance().has(&PROGRAM_DATA) {
+            return Err(Error::NotInitialized);
+        }
+        let program_data: ProgramData = env.storage().instance().get(&PROGRAM_DATA).unwrap();
+
+        let proposals = load_proposals(&env);
+        let proposal = proposals.get(proposal_id).ok_or(Error::ProposalNotFound)?;
+
+        if proposal.executed {
+            return Err(Error::ProposalAlreadyExecuted);
+        }
+        if env.ledger().timestamp() > proposal.expires_at {
+            return Err(Error::ProposalExpired);
+        }
+        if proposal.approvals.len() < program_data.threshold {
+            return Err(Error::Unauthorized);
+        }
+
+        execute_proposal(&env, proposal_id)?;
+
+        Ok(env.storage().instance().get(&PROGRAM_DATA).unwrap())
+    }
+
+    // ========================================================================
+    // Dispute & Claim
+    // ========================================================================
+
+    /// Cancels a pending payout before it's claimed, returning its amount
+    /// to `remaining_balance`.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `pending_payout_id` - Id of the entry to cancel
+    ///
+    /// # Returns
+    /// * `Ok(())` - Entry disputed and funds returned
+    /// * `Err(Error::NotInitialized)` - Program not initialized
+    /// * `Err(Error::PendingPayoutNotFound)` - `pending_payout_id` doesn't exist
+    /// * `Err(Error::PendingPayoutAlreadyClaimed)` - Entry was already claimed
+    /// * `Err(Error::PendingPayoutDisputed)` - Entry was already disputed
     ///
     /// # State Changes
-    /// - Transfers tokens from contract to recipient
-    /// - Adds PayoutRecord to history
-    /// - Decreases `remaining_balance` by amount
-    /// - Emits Payout event
+    /// - Marks the entry `disputed`
+    /// - Adds the entry's amount back to `remaining_balance`
+    /// - Emits PayoutDisputed event
     ///
-    /// # Security Considerations
-    /// - Verify recipient address before calling
-    /// - Amount must be positive
-    /// - Balance check prevents overdraft
-    /// - Transfer is logged in payout history
+    /// # Authorization
+    /// Requires `require_auth()` from the current `authorized_payout_key`
+    /// (the organizer).
     ///
     /// # Events
-    /// Emits: `Payout(program_id, recipient, amount, new_balance)`
+    /// Emits: `PayDispute(program_id, pending_payout_id, recipient, amount, new_remaining_balance)`
     ///
     /// # Example
     /// ```rust
-    /// use soroban_sdk::Address;
-    /// 
-    /// let winner = Address::from_string("GWINNER...");
-    /// let prize = 1_000_0000000; // $1,000 USDC
-    /// 
-    /// // Execute single payout
-    /// let result = escrow_client.single_payout(&winner, &prize);
-    /// println!("Paid {} to winner", prize);
+    /// // Wrong winner address was submitted - cancel before it claims
+    /// escrow_client.dispute_payout(&pending_payout_id);
     /// ```
+    pub fn dispute_payout(env: Env, pending_payout_id: u64) -> Result<(), Error> {
+        if !env.storage().instance().has(&PROGRAM_DATA) {
+            return Err(Error::NotInitialized);
+        }
+        let mut program_data: ProgramData = env.storage().instance().get(&PROGRAM_DATA).unwrap();
+        program_data.authorized_payout_key.require_auth();
+
+        let mut pending = load_pending_payouts(&env);
+        let mut entry = pending
+            .get(pending_payout_id)
+            .ok_or(Error::PendingPayoutNotFound)?;
+
+        if entry.claimed {
+            return Err(Error::PendingPayoutAlreadyClaimed);
+        }
+        if entry.disputed {
+            return Err(Error::PendingPayoutDisputed);
+        }
+
+        entry.disputed = true;
+        pending.set(pending_payout_id, entry.clone());
+        env.storage().persistent().set(&PENDING_PAYOUTS, &pending);
+
+        let mut balance = program_data
+            .balances
+            .get(entry.token.clone())
+            .ok_or(Error::TokenNotFunded)?;
+        balance.remaining_balance += entry.amount;
+        program_data
+            .balances
+            .set(entry.token.clone(), balance.clone());
+        env.storage().instance().set(&PROGRAM_DATA, &program_data);
+
+        env.events().publish(
+            (PAYOUT_DISPUTED,),
+            (
+                program_data.program_id.clone(),
+                pending_payout_id,
+                entry.recipient,
+                entry.amount,
+                balance.remaining_balance,
+            ),
+        );
+
+        Ok(())
+    }
+
+    /// Performs the actual token transfer for a pending payout once its
+    /// dispute window has elapsed, and records it in `payout_history`.
     ///
-    /// # Gas Cost
-    /// Medium - Single token transfer + storage update
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `pending_payout_id` - Id of the entry to claim
+    /// * `claimant` - Address invoking the claim; must be the entry's
+    ///   `recipient` or the current `authorized_payout_key`
     ///
-    /// # Use Cases
-    /// - Individual prize awards
-    /// - Bonus payments
-    /// - Late additions to prize pool distribution
-    pub fn single_payout(env: Env, recipient: Address, amount: i128) -> Result<ProgramData, Error> {
-        // Verify program is initialized
+    /// # Returns
+    /// * `Ok(())` - Transfer performed and recorded
+    /// * `Err(Error::NotInitialized)` - Program not initialized
+    /// * `Err(Error::PendingPayoutNotFound)` - `pending_payout_id` doesn't exist
+    /// * `Err(Error::Unauthorized)` - `claimant` is neither the recipient nor
+    ///   the authorized payout key
+    /// * `Err(Error::PendingPayoutDisputed)` - Entry was disputed and cancelled
+    /// * `Err(Error::PendingPayoutAlreadyClaimed)` - Entry was already claimed
+    /// * `Err(Error::PendingPayoutNotReleased)` - Called before `release_after`
+    ///
+    /// # State Changes
+    /// - Computes `fee = amount * fee_bps / FEE_BPS_DENOMINATOR`
+    ///   (`checked_mul`, zero if `fee_bps` is `0`)
+    /// - Transfers `amount - fee` from the contract to `recipient`, and
+    ///   `fee` to `treasury` if non-zero, in the same operation
+    /// - Adds `fee` to `total_fees_collected`
+    /// - Extends the payout hashchain (over the gross `amount`, unaffected
+    ///   by `fee_bps`) and appends a `PayoutRecord`
+    /// - Marks the entry `claimed`
+    /// - Emits PayoutClaimed event
+    ///
+    /// # Authorization
+    /// Requires `require_auth()` from `claimant`, which must match the
+    /// entry's `recipient` or the current `authorized_payout_key`.
+    ///
+    /// # Events
+    /// Emits: `PayClaimed(program_id, pending_payout_id, recipient, net_amount, fee, new_chain_head)`
+    ///
+    /// # Example
+    /// ```rust
+    /// // Called by the winner once the dispute window has passed
+    /// escrow_client.claim_payout(&pending_payout_id, &winner_address);
+    /// ```
+    pub fn claim_payout(env: Env, pending_payout_id: u64, claimant: Address) -> Result<(), Error> {
+        claimant.require_auth();
+
         if !env.storage().instance().has(&PROGRAM_DATA) {
             return Err(Error::NotInitialized);
         }
+        let mut program_data: ProgramData = env.storage().instance().get(&PROGRAM_DATA).unwrap();
 
-        // Get current program data
-        let program_data: ProgramData = env
-            .storage()
-            .instance()
-            .get(&PROGRAM_DATA)
-            .unwrap();
+        let mut pending = load_pending_payouts(&env);
+        let mut entry = pending
+            .get(pending_payout_id)
+            .ok_or(Error::PendingPayoutNotFound)?;
 
-        // Verify authorization
-        let caller = env.invoker();
-        if caller != program_data.authorized_payout_key {
+        if claimant != entry.recipient && claimant != program_data.authorized_payout_key {
             return Err(Error::Unauthorized);
         }
-
-        // Validate amount
-        if amount <= 0 {
-            return Err(Error::InvalidAmount);
+        if entry.disputed {
+            return Err(Error::PendingPayoutDisputed);
+        }
+        if entry.claimed {
+            return Err(Error::PendingPayoutAlreadyClaimed);
         }
 
-        // Validate sufficient balance
-        if amount > program_data.remaining_balance {
-            return Err(Error::InsufficientBalance);
+        let now = env.ledger().timestamp();
+        if now < entry.release_after {
+            return Err(Error::PendingPayoutNotReleased);
         }
 
-        // Transfer tokens to recipient
-        let contract_address = env.current_contract_address();
-        let token_client = token::Client::new(&env, &program_data.token_address);
-        token_client.transfer(&contract_address, &recipient, &amount);
-
-        // Record payout
-        let timestamp = env.ledger().timestamp();
-        let payout_record = PayoutRecord {
-            recipient: recipient.clone(),
-            amount,
-            timestamp,
+        let fee: i128 = if program_data.fee_bps == 0 {
+            0
+        } else {
+            entry
+                .amount
+                .checked_mul(program_data.fee_bps as i128)
+                .ok_or(Error::InvalidAmount)?
+                / FEE_BPS_DENOMINATOR
         };
+        let net_amount = entry.amount - fee;
+
+        guard_enter(&env)?;
+
+        // Checks-effects-interactions: persist the claim and hashchain state
+        // before the external token transfers so a reentrant call sees the
+        // entry already claimed.
+        let chain_head = next_chain_link(
+            &env,
+            &program_data.chain_head,
+            &entry.recipient,
+            entry.amount,
+            now,
+        );
+        program_data.payout_count = append_payout_record(
+            &env,
+            program_data.payout_count,
+            &PayoutRecord {
+                recipient: entry.recipient.clone(),
+                token: entry.token.clone(),
+                amount: entry.amount,
+                fee,
+                timestamp: now,
+                prev_hash: chain_head.clone(),
+            },
+        );
+        program_data.chain_head = chain_head.clone();
+        program_data.total_fees_collected += fee;
+        env.storage().instance().set(&PROGRAM_DATA, &program_data);
 
-        let mut updated_history = program_data.payout_history.clone();
-        updated_history.push_back(payout_record);
+        entry.claimed = true;
+        pending.set(pending_payout_id, entry.clone());
+        env.storage().persistent().set(&PENDING_PAYOUTS, &pending);
 
-        // Update program data
-        let mut updated_data = program_data.clone();
-        updated_data.remaining_balance -= amount;
-        updated_data.payout_history = updated_history;
+        let contract_address = env.current_contract_address();
+        let token_client = token::Client::new(&env, &entry.token);
+        token_client.transfer(&contract_address, &entry.recipient, &net_amount);
+        if fee > 0 {
+            token_client.transfer(&contract_address, &program_data.treasury, &fee);
+        }
 
-        // Store updated data
-        env.storage()
-            .instance()
-            .set(&PROGRAM_DATA, &updated_data);
+        guard_exit(&env);
 
-        // Emit payout event
         env.events().publish(
-            (PAYOUT,),
+            (PAYOUT_CLAIMED,),
             (
-                updated_data.program_id.clone(),
+                program_data.program_id.clone(),
+                pending_payout_id,
+                entry.recipient,
+                net_amount,
+                fee,
+                chain_head,
+            ),
+        );
+
+        Ok(())
+    }
+
+    // ========================================================================
+    // Vesting
+    // ========================================================================
+
+    /// Creates a milestone-based vesting schedule for a single recipient,
+    /// reserving no funds up front but validating that `total` could be
+    /// paid out of the current `remaining_balance`.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `recipient` - Address the schedule is created for
+    /// * `token` - Token the schedule will pay out in; must already have
+    ///   been funded via `lock_program_funds`
+    /// * `total` - Sum every tranche amount must add up to
+    /// * `release_ats` - Tranche release timestamps, strictly increasing
+    /// * `amounts` - Tranche amounts, matching `release_ats` by index
+    ///
+    /// # Returns
+    /// * `Ok(())` - Schedule created
+    /// * `Err(Error::NotInitialized)` - Program not initialized
+    /// * `Err(Error::VestingScheduleExists)` - `recipient` already has a schedule
+    /// * `Err(Error::TokenNotFunded)` - `token` has never been locked via `lock_program_funds`
+    /// * `Err(Error::BatchMismatch)` - `release_ats`/`amounts` length mismatch (or empty)
+    /// * `Err(Error::InvalidAmount)` - A tranche amount is zero or negative
+    /// * `Err(Error::VestingScheduleNotIncreasing)` - `release_ats` isn't strictly increasing
+    /// * `Err(Error::VestingTotalMismatch)` - Tranche amounts don't sum to `total`
+    /// * `Err(Error::InsufficientBalance)` - `total` exceeds the token's `remaining_balance`
+    ///
+    /// # State Changes
+    /// - Stores a `VestedSchedule` with every tranche `claimed: false`
+    /// - Does **not** touch `remaining_balance`; each tranche is debited
+    ///   from it only once `claim_vested` actually releases it
+    ///
+    /// # Authorization
+    /// Requires `require_auth()` from the current `authorized_payout_key`
+    /// (the organizer).
+    ///
+    /// # Events
+    /// Emits: `None` - tranches are observable via `get_vested_schedule`;
+    /// `TrancheRel` fires per tranche as it's claimed
+    ///
+    /// # Example
+    /// ```rust
+    /// // 25% at each of four quarterly milestones
+    /// let release_ats = vec![&env, q1_ts, q2_ts, q3_ts, q4_ts];
+    /// let amounts = vec![&env, 250_0000000, 250_0000000, 250_0000000, 250_0000000];
+    /// escrow_client.schedule_vested_payout(&grantee, &usdc, &1_000_0000000, &release_ats, &amounts);
+    /// ```
+    pub fn schedule_vested_payout(
+        env: Env,
+        recipient: Address,
+        token: Address,
+        total: i128,
+        release_ats: Vec<u64>,
+        amounts: Vec<i128>,
+    ) -> Result<(), Error> {
+        if !env.storage().instance().has(&PROGRAM_DATA) {
+            return Err(Error::NotInitialized);
+        }
+        let program_data: ProgramData = env.storage().instance().get(&PROGRAM_DATA).unwrap();
+        program_data.authorized_payout_key.require_auth();
+
+        let balance = program_data
+            .balances
+            .get(token.clone())
+            .ok_or(Error::TokenNotFunded)?;
+
+        let mut schedules = load_vested_schedules(&env);
+        if schedules.contains_key(recipient.clone()) {
+            return Err(Error::VestingScheduleExists);
+        }
+
+        if release_ats.len() != amounts.len() || release_ats.is_empty() {
+            return Err(Error::BatchMismatch);
+        }
+
+        let mut tranches = Vec::new(&env);
+        let mut running_total: i128 = 0;
+        let mut previous_release_at: Option<u64> = None;
+        for i in 0..release_ats.len() {
+            let release_at = release_ats.get(i).unwrap();
+            let amount = amounts.get(i).unwrap();
+
+            if amount <= 0 {
+                return Err(Error::InvalidAmount);
+            }
+            if let Some(previous) = previous_release_at {
+                if release_at <= previous {
+                    return Err(Error::VestingScheduleNotIncreasing);
+                }
+            }
+            previous_release_at = Some(release_at);
+
+            running_total = running_total
+                .checked_add(amount)
+                .ok_or(Error::InvalidAmount)?;
+
+            tranches.push_back(VestingTranche {
+                release_at,
+                amount,
+                claimed: false,
+            });
+        }
+
+        if running_total != total {
+            return Err(Error::VestingTotalMismatch);
+        }
+        if total > balance.remaining_balance {
+            return Err(Error::InsufficientBalance);
+        }
+
+        schedules.set(
+            recipient.clone(),
+            VestedSchedule {
                 recipient,
+                token,
+                total,
+                tranches,
+            },
+        );
+        env.storage().persistent().set(&VESTED_SCHEDULES, &schedules);
+
+        Ok(())
+    }
+
+    /// Releases every tranche of `recipient`'s vesting schedule whose
+    /// `release_at` has passed and that hasn't already been claimed.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `recipient` - Recipient whose schedule is being drawn down
+    /// * `claimant` - Address invoking the claim; must be `recipient` or
+    ///   the current `authorized_payout_key`
+    ///
+    /// # Returns
+    /// * `Ok(i128)` - Total amount released by this call (`0` if no tranche
+    ///   was yet due)
+    /// * `Err(Error::NotInitialized)` - Program not initialized
+    /// * `Err(Error::VestingScheduleNotFound)` - `recipient` has no schedule
+    /// * `Err(Error::Unauthorized)` - `claimant` is neither `recipient` nor
+    ///   the authorized payout key
+    ///
+    /// # State Changes
+    /// - Marks every due, unclaimed tranche `claimed`
+    /// - Transfers each due tranche's amount to `recipient`
+    /// - Decrements `remaining_balance` by the total released (tranches
+    ///   not yet due are never reserved, so the balance only moves as
+    ///   funds actually leave the contract)
+    /// - Extends the payout hashchain and appends a `PayoutRecord` per
+    ///   released tranche (`fee: 0`; the protocol fee only applies to
+    ///   `claim_payout`)
+    /// - Emits one `TrancheRel` event per released tranche
+    ///
+    /// # Authorization
+    /// Requires `require_auth()` from `claimant`.
+    ///
+    /// # Events
+    /// Emits: `TrancheRel(program_id, recipient, release_at, amount, new_chain_head)`
+    /// once per released tranche
+    ///
+    /// # Example
+    /// ```rust
+    /// // Called after a milestone's release_at has passed
+    /// escrow_client.claim_vested(&grantee, &grantee);
+    /// ```
+    pub fn claim_vested(env: Env, recipient: Address, claimant: Address) -> Result<i128, Error> {
+        claimant.require_auth();
+
+        if !env.storage().instance().has(&PROGRAM_DATA) {
+            return Err(Error::NotInitialized);
+        }
+        let mut program_data: ProgramData = env.storage().instance().get(&PROGRAM_DATA).unwrap();
+
+        if claimant != recipient && claimant != program_data.authorized_payout_key {
+            return Err(Error::Unauthorized);
+        }
+
+        let mut schedules = load_vested_schedules(&env);
+        let mut schedule = schedules
+            .get(recipient.clone())
+            .ok_or(Error::VestingScheduleNotFound)?;
+
+        let now = env.ledger().timestamp();
+        let contract_address = env.current_contract_address();
+        let token_client = token::Client::new(&env, &schedule.token);
+        let mut balance = program_data
+            .balances
+            .get(schedule.token.clone())
+            .ok_or(Error::TokenNotFunded)?;
+
+        guard_enter(&env)?;
+
+        let mut released_total: i128 = 0;
+        for index in 0..schedule.tranches.len() {
+            let tranche = schedule.tranches.get(index).unwrap();
+            if tranche.claimed || tranche.release_at > now {
+                continue;
+            }
+
+            let chain_head = next_chain_link(
+                &env,
+                &program_data.chain_head,
+                &recipient,
+                tranche.amount,
+                now,
+            );
+            program_data.payout_count = append_payout_record(
+                &env,
+                program_data.payout_count,
+                &PayoutRecord {
+                    recipient: recipient.clone(),
+                    token: schedule.token.clone(),
+                    amount: tranche.amount,
+                    fee: 0,
+                    timestamp: now,
+                    prev_hash: chain_head.clone(),
+                },
+            );
+            program_data.chain_head = chain_head.clone();
+            balance.remaining_balance -= tranche.amount;
+            released_total += tranche.amount;
+
+            // Checks-effects-interactions: persist this tranche as claimed,
+            // plus the hashchain/balance state, before the external transfer
+            // for it so a reentrant call can't claim the same tranche twice.
+            schedule.tranches.set(
+                index,
+                VestingTranche {
+                    release_at: tranche.release_at,
+                    amount: tranche.amount,
+                    claimed: true,
+                },
+            );
+            schedules.set(recipient.clone(), schedule.clone());
+            env.storage().persistent().set(&VESTED_SCHEDULES, &schedules);
+            program_data.balances.set(schedule.token.clone(), balance.clone());
+            env.storage().instance().set(&PROGRAM_DATA, &program_data);
+
+            token_client.transfer(&contract_address, &recipient, &tranche.amount);
+
+            env.events().publish(
+                (TRANCHE_RELEASED,),
+                (
+                    program_data.program_id.clone(),
+                    recipient.clone(),
+                    tranche.release_at,
+                    tranche.amount,
+                    chain_head,
+                ),
+            );
+        }
+
+        guard_exit(&env);
+        Ok(released_total)
+    }
+
+    // ========================================================================
+    // Idempotent Backend Payouts
+    // ========================================================================
+
+    /// Performs a direct batch payout keyed by a caller-supplied
+    /// `request_id`, so a backend retrying after a timeout can call this
+    /// again with the same id and get a no-op instead of a double payment.
+    ///
+    /// Unlike `propose_payout`/`approve_payout`/`execute_payout`, this
+    /// bypasses the signer quorum and dispute window entirely - it is a
+    /// direct channel for the trusted backend, and transfers happen
+    /// immediately.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `caller` - Must be the current `authorized_payout_key` (must authorize)
+    /// * `request_id` - Caller-chosen unique id for this batch; a second
+    ///   call with the same id is a no-op regardless of `recipients`/`amounts`
+    /// * `token` - Token the batch pays out in; must already have been
+    ///   funded via `lock_program_funds`
+    /// * `nonce` - Must equal `payout_nonce`, the expected next value, for a
+    ///   fresh call; guards against an out-of-order or replayed signed
+    ///   instruction reaching the contract. Not checked on a retry of an
+    ///   already-processed `request_id` - `payout_nonce` has moved on by
+    ///   then, and the retry is still signed with the original nonce
+    /// * `recipients` - Vector of recipient addresses
+    /// * `amounts` - Vector of gross amounts (pre-fee), matching `recipients` by index
+    ///
+    /// # Returns
+    /// * `Ok(())` - Either the batch was paid out, or `request_id` was already processed
+    /// * `Err(Error::NotInitialized)` - Program not initialized
+    /// * `Err(Error::Unauthorized)` - `caller` is not the authorized payout key
+    /// * `Err(Error::InvalidNonce)` - `nonce` doesn't equal `payout_nonce`, for a
+    ///   fresh `request_id`
+    /// * `Err(Error::TokenNotFunded)` - `token` has never been locked via `lock_program_funds`
+    /// * `Err(Error::BatchMismatch)` - Recipients and amounts vectors length mismatch (or empty)
+    /// * `Err(Error::InvalidAmount)` - An amount is zero or negative
+    /// * `Err(Error::InsufficientBalance)` - Total payout exceeds the token's remaining balance
+    /// * `Err(Error::PayoutLimitExceeded)` - Recipients, an individual amount, or the
+    ///   total exceeds a limit set via `set_payout_limits`
+    ///
+    /// # State Changes
+    /// - If `request_id` was already processed: none, besides bumping its TTL
+    ///   (checked before `nonce`, so a legitimate retry signed with the
+    ///   original nonce still succeeds as a no-op once `payout_nonce` has
+    ///   since advanced)
+    /// - Otherwise: transfers each recipient's net amount (after `fee_bps`)
+    ///   and the fee portion to `treasury`, decrements `remaining_balance`
+    ///   by the gross total, extends the payout hashchain and appends one
+    ///   `PayoutRecord` per recipient, adds to `total_fees_collected`,
+    ///   increments `payout_nonce`, and marks `request_id` processed in
+    ///   persistent storage
+    ///
+    /// # Authorization
+    /// Requires `require_auth()` from `caller`, which must be the current
+    /// `authorized_payout_key`.
+    ///
+    /// # Events
+    /// Emits: `PayClaimed(program_id, request_id, recipient, net_amount, fee, new_chain_head)`
+    /// once per recipient on a fresh request; none on a replayed one
+    ///
+    /// # Example
+    /// ```rust
+    /// let request_id = compute_idempotency_key(&batch_job_id);
+    /// escrow_client.batch_payout_idempotent(&backend, &request_id, &usdc, &0, &winners, &prizes);
+    /// // The next signed batch must advance the nonce:
+    /// let next_request_id = compute_idempotency_key(&next_batch_job_id);
+    /// escrow_client.batch_payout_idempotent(&backend, &next_request_id, &usdc, &1, &winners, &prizes);
+    /// ```
+    pub fn batch_payout_idempotent(
+        env: Env,
+        caller: Address,
+        request_id: BytesN<32>,
+        token: Address,
+        nonce: u64,
+        recipients: Vec<Address>,
+        amounts: Vec<i128>,
+    ) -> Result<(), Error> {
+        caller.require_auth();
+
+        if !env.storage().instance().has(&PROGRAM_DATA) {
+            return Err(Error::NotInitialized);
+        }
+        let mut program_data: ProgramData = env.storage().instance().get(&PROGRAM_DATA).unwrap();
+
+        if caller != program_data.authorized_payout_key {
+            return Err(Error::Unauthorized);
+        }
+
+        // Check the request_id dedup first: a retry of an already-executed
+        // call must stay a safe no-op even though payout_nonce has already
+        // advanced past the nonce that retry was originally signed with.
+        if env.storage().persistent().has(&request_id) {
+            env.storage().persistent().extend_ttl(
+                &request_id,
+                PROCESSED_REQUEST_TTL_THRESHOLD,
+                PROCESSED_REQUEST_TTL_EXTEND_TO,
+            );
+            return Ok(());
+        }
+
+        if nonce != program_data.payout_nonce {
+            return Err(Error::InvalidNonce);
+        }
+
+        let mut balance = program_data
+            .balances
+            .get(token.clone())
+            .ok_or(Error::TokenNotFunded)?;
+
+        if recipients.len() != amounts.len() || recipients.is_empty() {
+            return Err(Error::BatchMismatch);
+        }
+        if let Some(max_recipients) = program_data.max_batch_recipients {
+            if recipients.len() > max_recipients {
+                return Err(Error::PayoutLimitExceeded);
+            }
+        }
+
+        let mut total_payout: i128 = 0;
+        for amount in amounts.iter() {
+            if amount <= 0 {
+                return Err(Error::InvalidAmount);
+            }
+            if let Some(max_single) = program_data.max_single_payout {
+                if amount > max_single {
+                    return Err(Error::PayoutLimitExceeded);
+                }
+            }
+            total_payout = total_payout
+                .checked_add(amount)
+                .ok_or(Error::InvalidAmount)?;
+        }
+        if total_payout > balance.remaining_balance {
+            return Err(Error::InsufficientBalance);
+        }
+        if let Some(max_total) = program_data.max_batch_total {
+            if total_payout > max_total {
+                return Err(Error::PayoutLimitExceeded);
+            }
+        }
+
+        let now = env.ledger().timestamp();
+        let contract_address = env.current_contract_address();
+        let token_client = token::Client::new(&env, &token);
+
+        guard_enter(&env)?;
+
+        // Checks-effects-interactions: compute every recipient's net payout
+        // and this batch's hashchain/balance/nonce/request_id state first,
+        // and persist all of it, before making any external transfer - a
+        // reentrant call then sees the nonce already advanced and this
+        // request_id already marked processed.
+        let mut net_amounts: Vec<i128> = Vec::new(&env);
+        let mut fees: Vec<i128> = Vec::new(&env);
+        let mut chain_heads: Vec<BytesN<32>> = Vec::new(&env);
+        for (i, recipient) in recipients.iter().enumerate() {
+            let amount = amounts.get(i).unwrap();
+
+            let fee: i128 = if program_data.fee_bps == 0 {
+                0
+            } else {
+                amount
+                    .checked_mul(program_data.fee_bps as i128)
+                    .ok_or(Error::InvalidAmount)?
+                    / FEE_BPS_DENOMINATOR
+            };
+            let net_amount = amount - fee;
+            net_amounts.push_back(net_amount);
+            fees.push_back(fee);
+
+            let chain_head = next_chain_link(
+                &env,
+                &program_data.chain_head,
+                &recipient,
                 amount,
-                updated_data.remaining_balance,
-            ),
+                now,
+            );
+            program_data.payout_count = append_payout_record(
+                &env,
+                program_data.payout_count,
+                &PayoutRecord {
+                    recipient: recipient.clone(),
+                    token: token.clone(),
+                    amount,
+                    fee,
+                    timestamp: now,
+                    prev_hash: chain_head.clone(),
+                },
+            );
+            program_data.chain_head = chain_head.clone();
+            program_data.total_fees_collected += fee;
+            chain_heads.push_back(chain_head);
+        }
+
+        balance.remaining_balance -= total_payout;
+        program_data.balances.set(token, balance);
+        program_data.payout_nonce += 1;
+        env.storage().instance().set(&PROGRAM_DATA, &program_data);
+
+        env.storage().persistent().set(&request_id, &true);
+        env.storage().persistent().extend_ttl(
+            &request_id,
+            PROCESSED_REQUEST_TTL_THRESHOLD,
+            PROCESSED_REQUEST_TTL_EXTEND_TO,
         );
 
-        Ok(updated_data)
+        for (i, recipient) in recipients.iter().enumerate() {
+            let net_amount = net_amounts.get(i).unwrap();
+            let fee = fees.get(i).unwrap();
+
+            token_client.transfer(&contract_address, &recipient, &net_amount);
+            if fee > 0 {
+                token_client.transfer(&contract_address, &program_data.treasury, &fee);
+            }
+
+            env.events().publish(
+                (PAYOUT_CLAIMED,),
+                (
+                    program_data.program_id.clone(),
+                    request_id.clone(),
+                    recipient,
+                    net_amount,
+                    fee,
+                    chain_heads.get(i).unwrap(),
+                ),
+            );
+        }
+
+        guard_exit(&env);
+        Ok(())
+    }
+
+    /// Checks whether a `batch_payout_idempotent` request id has already
+    /// been processed.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `request_id` - Id to check
+    ///
+    /// # Returns
+    /// `true` if a batch was already paid out (or no-op'd) under this id
+    ///
+    /// # Use Cases
+    /// - Backend reconciliation after a crash, before deciding whether to retry
+    pub fn is_request_processed(env: Env, request_id: BytesN<32>) -> bool {
+        env.storage().persistent().has(&request_id)
     }
 
     // ========================================================================
@@ -1069,26 +2969,25 @@ impl ProgramEscrowContract {
     /// # Returns
     /// * `Ok(ProgramData)` - Complete program state including:
     ///   - Program ID
-    ///   - Total funds locked
-    ///   - Remaining balance
+    ///   - Per-token total funds locked / remaining balance (`balances`)
     ///   - Authorized payout key
-    ///   - Complete payout history
-    ///   - Token contract address
+    ///   - `payout_count`, the total number of recorded payouts (use
+    ///     `get_payout_history` to page through the records themselves)
     /// * `Err(Error::NotInitialized)` - Program not initialized
     ///
     /// # Use Cases
     /// - Verifying program configuration
     /// - Checking balances before payouts
-    /// - Auditing payout history
     /// - Displaying program status in UI
     ///
     /// # Example
     /// ```rust
     /// let info = escrow_client.get_program_info();
     /// println!("Program: {}", info.program_id);
-    /// println!("Total Locked: {}", info.total_funds);
-    /// println!("Remaining: {}", info.remaining_balance);
-    /// println!("Payouts Made: {}", info.payout_history.len());
+    /// for (token, balance) in info.balances.iter() {
+    ///     println!("{:?}: {} remaining of {} locked", token, balance.remaining_balance, balance.total_funds);
+    /// }
+    /// println!("Payouts Made: {}", info.payout_count);
     /// ```
     ///
     /// # Gas Cost
@@ -1146,8 +3045,8 @@ impl ProgramEscrowContract {
     /// ```rust
     /// let program_view = escrow_client.get_program_with_metadata();
     /// println!("Program: {}", program_view.program.program_id);
-    /// println!("Balance: {}", program_view.program.remaining_balance);
-    /// 
+    /// println!("Tokens funded: {}", program_view.program.balances.len());
+    ///
     /// if let Some(meta) = program_view.metadata {
     ///     println!("Event: {:?}", meta.event_name);
     ///     println!("Website: {:?}", meta.website);
@@ -1166,17 +3065,19 @@ impl ProgramEscrowContract {
         })
     }
 
-    /// Retrieves the remaining balance available in the program.
+    /// Retrieves the remaining balance of one token available in the program.
     ///
-    /// This function returns the amount of funds still locked in the program
-    /// and available for future payouts.
+    /// This function returns the amount of that token still locked in the
+    /// program and available for future payouts.
     ///
     /// # Arguments
     /// * `env` - The contract environment
+    /// * `token` - Token to look up
     ///
     /// # Returns
     /// * `Ok(i128)` - Remaining token balance that has not been paid out
     /// * `Err(Error::NotInitialized)` - Program not initialized
+    /// * `Err(Error::TokenNotFunded)` - `token` has never been locked via `lock_program_funds`
     ///
     /// # Use Cases
     /// - Checking available funds before initiating a payout
@@ -1185,7 +3086,7 @@ impl ProgramEscrowContract {
     ///
     /// # Example
     /// ```rust
-    /// let remaining = escrow_client.get_remaining_balance();
+    /// let remaining = escrow_client.get_remaining_balance(&usdc);
     /// println!("Remaining balance: {}", remaining);
     /// ```
     ///
@@ -1195,17 +3096,489 @@ impl ProgramEscrowContract {
     ///
     /// # Gas Cost
     /// Very Low - Single storage read
-    pub fn get_remaining_balance(env: Env) -> Result<i128, Error> {
+    pub fn get_remaining_balance(env: Env, token: Address) -> Result<i128, Error> {
         if !env.storage().instance().has(&PROGRAM_DATA) {
             return Err(Error::NotInitialized);
         }
-        
+
+        let program_data: ProgramData = env
+            .storage()
+            .instance()
+            .get(&PROGRAM_DATA)
+            .unwrap();
+
+        program_data
+            .balances
+            .get(token)
+            .map(|b| b.remaining_balance)
+            .ok_or(Error::TokenNotFunded)
+    }
+
+    /// Alias for `get_remaining_balance`, kept under this name for callers
+    /// that know the program by its per-token balance lookups rather than
+    /// its single-balance history.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `token` - Token to look up
+    ///
+    /// # Returns
+    /// Same as `get_remaining_balance`.
+    pub fn get_remaining_balance_for(env: Env, token: Address) -> Result<i128, Error> {
+        Self::get_remaining_balance(env, token)
+    }
+
+    /// Retrieves the remaining balance of every token the program has ever
+    /// been funded in.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    ///
+    /// # Returns
+    /// * `Ok(Map<Address, i128>)` - Remaining balance per funded token
+    /// * `Err(Error::NotInitialized)` - Program not initialized
+    ///
+    /// # Use Cases
+    /// - Displaying a multi-token prize pool's full balance sheet in one call
+    /// - Auditing solvency across every token funded into the program
+    ///
+    /// # Example
+    /// ```rust
+    /// let balances = escrow_client.get_balances();
+    /// for (token, remaining) in balances.iter() {
+    ///     println!("{:?}: {}", token, remaining);
+    /// }
+    /// ```
+    pub fn get_balances(env: Env) -> Result<Map<Address, i128>, Error> {
+        if !env.storage().instance().has(&PROGRAM_DATA) {
+            return Err(Error::NotInitialized);
+        }
+
+        let program_data: ProgramData = env
+            .storage()
+            .instance()
+            .get(&PROGRAM_DATA)
+            .unwrap();
+
+        let mut balances = Map::new(&env);
+        for (token, balance) in program_data.balances.iter() {
+            balances.set(token, balance.remaining_balance);
+        }
+
+        Ok(balances)
+    }
+
+    /// Returns the total number of payouts ever recorded, across every token.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    ///
+    /// # Returns
+    /// * `Ok(u32)` - Total payout count
+    /// * `Err(Error::NotInitialized)` - Program not initialized
+    ///
+    /// # Use Cases
+    /// - Deciding how many pages `get_payout_history` needs to walk to
+    ///   cover the full history
+    ///
+    /// # Example
+    /// ```rust
+    /// let count = escrow_client.get_payout_count();
+    /// ```
+    pub fn get_payout_count(env: Env) -> Result<u32, Error> {
+        if !env.storage().instance().has(&PROGRAM_DATA) {
+            return Err(Error::NotInitialized);
+        }
+
+        let program_data: ProgramData = env.storage().instance().get(&PROGRAM_DATA).unwrap();
+        Ok(program_data.payout_count)
+    }
+
+    /// Reads a page of the payout history without loading every record at once.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `start` - Index of the first record to return (0-based, oldest first)
+    /// * `limit` - Maximum number of records to return
+    ///
+    /// # Returns
+    /// * `Ok(Vec<PayoutRecord>)` - Up to `limit` records starting at `start`,
+    ///   fewer if `start + limit` exceeds `get_payout_count`
+    /// * `Err(Error::NotInitialized)` - Program not initialized
+    ///
+    /// # Use Cases
+    /// - Paginated UI/backend rendering of payout history without pulling
+    ///   the entire (potentially very long-lived) history into memory at once
+    ///
+    /// # Example
+    /// ```rust
+    /// let count = escrow_client.get_payout_count();
+    /// let mut start = 0u32;
+    /// while start < count {
+    ///     let page = escrow_client.get_payout_history(&start, &100u32);
+    ///     start += page.len() as u32;
+    /// }
+    /// ```
+    pub fn get_payout_history(env: Env, start: u32, limit: u32) -> Result<Vec<PayoutRecord>, Error> {
+        if !env.storage().instance().has(&PROGRAM_DATA) {
+            return Err(Error::NotInitialized);
+        }
+
+        let program_data: ProgramData = env.storage().instance().get(&PROGRAM_DATA).unwrap();
+        let end = start.saturating_add(limit).min(program_data.payout_count);
+
+        let mut records = Vec::new(&env);
+        let mut i = start;
+        while i < end {
+            if let Some(record) = env.storage().persistent().get(&DataKey::Payout(i)) {
+                records.push_back(record);
+            }
+            i += 1;
+        }
+
+        Ok(records)
+    }
+
+    /// Retrieves the cumulative protocol fees skimmed to `treasury` so far.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    ///
+    /// # Returns
+    /// * `Ok(i128)` - Total fees collected across all claimed payouts
+    /// * `Err(Error::NotInitialized)` - Program not initialized
+    ///
+    /// # Use Cases
+    /// - Auditing exactly how much was skimmed over the program's lifetime
+    /// - Reconciling `treasury`'s on-chain balance against contract state
+    pub fn get_total_fees_collected(env: Env) -> Result<i128, Error> {
+        if !env.storage().instance().has(&PROGRAM_DATA) {
+            return Err(Error::NotInitialized);
+        }
+
         let program_data: ProgramData = env
             .storage()
             .instance()
             .get(&PROGRAM_DATA)
             .unwrap();
 
-        Ok(program_data.remaining_balance)
+        Ok(program_data.total_fees_collected)
+    }
+
+    /// Retrieves a payout proposal by id.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `proposal_id` - Id returned by `propose_payout`
+    ///
+    /// # Returns
+    /// * `Ok(PayoutProposal)` - The proposal's current state, including its
+    ///   accumulated approvals and whether it has executed
+    /// * `Err(Error::NotInitialized)` - Program not initialized
+    /// * `Err(Error::ProposalNotFound)` - `proposal_id` doesn't exist
+    ///
+    /// # Use Cases
+    /// - Checking how many approvals a pending proposal still needs
+    /// - Confirming whether a proposal has executed or expired
+    pub fn get_payout_proposal(env: Env, proposal_id: u64) -> Result<PayoutProposal, Error> {
+        if !env.storage().instance().has(&PROGRAM_DATA) {
+            return Err(Error::NotInitialized);
+        }
+
+        load_proposals(&env).get(proposal_id).ok_or(Error::ProposalNotFound)
+    }
+
+    /// Retrieves a pending payout by id.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `pending_payout_id` - Id assigned when the owning proposal executed
+    ///
+    /// # Returns
+    /// * `Ok(PendingPayout)` - The entry's current state
+    /// * `Err(Error::NotInitialized)` - Program not initialized
+    /// * `Err(Error::PendingPayoutNotFound)` - `pending_payout_id` doesn't exist
+    ///
+    /// # Use Cases
+    /// - Checking whether an entry is still disputable (not yet claimed) and
+    ///   when it becomes claimable (`release_after`)
+    /// - Confirming whether an entry has already been disputed or claimed
+    pub fn get_pending_payout(env: Env, pending_payout_id: u64) -> Result<PendingPayout, Error> {
+        if !env.storage().instance().has(&PROGRAM_DATA) {
+            return Err(Error::NotInitialized);
+        }
+
+        load_pending_payouts(&env)
+            .get(pending_payout_id)
+            .ok_or(Error::PendingPayoutNotFound)
+    }
+
+    /// Retrieves a recipient's vesting schedule.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `recipient` - Recipient the schedule was created for
+    ///
+    /// # Returns
+    /// * `Ok(VestedSchedule)` - The schedule's current state
+    /// * `Err(Error::NotInitialized)` - Program not initialized
+    /// * `Err(Error::VestingScheduleNotFound)` - `recipient` has no schedule
+    ///
+    /// # Use Cases
+    /// - Checking which tranches are still outstanding and when they unlock
+    /// - Confirming a schedule's `total` before calling `claim_vested`
+    pub fn get_vested_schedule(env: Env, recipient: Address) -> Result<VestedSchedule, Error> {
+        if !env.storage().instance().has(&PROGRAM_DATA) {
+            return Err(Error::NotInitialized);
+        }
+
+        load_vested_schedules(&env)
+            .get(recipient)
+            .ok_or(Error::VestingScheduleNotFound)
+    }
+
+    /// Verifies a candidate payout history against the on-chain hashchain.
+    ///
+    /// Recomputes the chain from genesis (all-zero bytes) by replaying
+    /// `records` in order and rederiving each link via the same
+    /// `sha256(chain_head || recipient || amount.to_be_bytes() ||
+    /// timestamp.to_be_bytes())` construction used by `claim_payout`,
+    /// then checks that the replayed head matches the `chain_head` currently
+    /// stored in `ProgramData`.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `records` - Candidate payout history to verify, typically pulled
+    ///   from an indexer rather than read directly from this contract
+    ///
+    /// # Returns
+    /// * `true` - `records` reproduces the stored `chain_head` exactly; no
+    ///   entry was dropped, reordered, or altered
+    /// * `false` - The replayed chain diverges from a record's `prev_hash`,
+    ///   or does not match the stored `chain_head`, or the program isn't
+    ///   initialized
+    ///
+    /// # Use Cases
+    /// - Confirming an off-chain copy of `payout_history` hasn't been
+    ///   tampered with, without re-reading every entry from this contract
+    /// - Auditing after a contract upgrade that a past record wasn't
+    ///   silently rewritten
+    ///
+    /// # Gas Cost
+    /// Linear in `records.len()` - one hash per record
+    pub fn verify_payout_chain(env: Env, records: Vec<PayoutRecord>) -> bool {
+        if !env.storage().instance().has(&PROGRAM_DATA) {
+            return false;
+        }
+        let program_data: ProgramData = env.storage().instance().get(&PROGRAM_DATA).unwrap();
+
+        let mut chain_head = BytesN::from_array(&env, &[0u8; 32]);
+        for record in records.iter() {
+            chain_head = next_chain_link(&env, &chain_head, &record.recipient, record.amount, record.timestamp);
+            if chain_head != record.prev_hash {
+                return false;
+            }
+        }
+
+        chain_head == program_data.chain_head
+    }
+
+    /// Retrieves the current head of the payout hashchain, i.e. the
+    /// `prev_hash` of the most recently appended `PayoutRecord` (or the
+    /// all-zero genesis if no payout has ever been recorded).
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    ///
+    /// # Returns
+    /// * `Ok(BytesN<32>)` - The current `chain_head`
+    /// * `Err(Error::NotInitialized)` - Program not initialized
+    ///
+    /// # Use Cases
+    /// - Letting an off-chain indexer follow the chain incrementally,
+    ///   fetching only records newer than the last head it observed,
+    ///   instead of re-verifying the full history via `verify_payout_chain`
+    ///   on every poll
+    pub fn get_payout_chain_head(env: Env) -> Result<BytesN<32>, Error> {
+        if !env.storage().instance().has(&PROGRAM_DATA) {
+            return Err(Error::NotInitialized);
+        }
+
+        let program_data: ProgramData = env.storage().instance().get(&PROGRAM_DATA).unwrap();
+        Ok(program_data.chain_head)
+    }
+
+    // ========================================================================
+    // Migration
+    // ========================================================================
+
+    /// One-time migration for a program initialized before multi-token
+    /// support: reads the old single-token `ProgramData` shape and rewraps
+    /// its `token_address`/`total_funds`/`remaining_balance` as a one-entry
+    /// `balances` map, preserving every other field as-is. Its `payout_history`
+    /// is replayed directly into the paginated `DataKey::Payout` layout, so a
+    /// program migrating for the first time never needs a separate
+    /// `migrate_payout_history` call afterward.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    ///
+    /// # Returns
+    /// * `Ok(ProgramData)` - The migrated, multi-token-shaped program data
+    /// * `Err(Error::NotInitialized)` - Program not initialized
+    /// * `Err(Error::AlreadyMigrated)` - This program has already migrated
+    ///
+    /// # State Changes
+    /// - Writes one persistent entry per historical payout record
+    /// - Replaces the stored `ProgramData` with the new multi-token,
+    ///   paginated-history shape
+    /// - Sets flags preventing this function and `migrate_payout_history`
+    ///   from running again
+    ///
+    /// # Authorization
+    /// Requires `require_auth()` from the current `authorized_payout_key`
+    /// (the organizer), matching the gating used by `rotate_payout_key` and
+    /// `dispute_payout`.
+    ///
+    /// # Example
+    /// ```rust
+    /// // Run once against a program deployed before this upgrade
+    /// let migrated = escrow_client.migrate_to_multi_token();
+    /// let usdc_balance = migrated.balances.get(usdc_token).unwrap();
+    /// ```
+    pub fn migrate_to_multi_token(env: Env) -> Result<ProgramData, Error> {
+        if env.storage().instance().has(&MIGRATED_MULTI_TOKEN) {
+            return Err(Error::AlreadyMigrated);
+        }
+        if !env.storage().instance().has(&PROGRAM_DATA) {
+            return Err(Error::NotInitialized);
+        }
+
+        let legacy: LegacyProgramData = env.storage().instance().get(&PROGRAM_DATA).unwrap();
+        legacy.authorized_payout_key.require_auth();
+
+        let mut balances = Map::new(&env);
+        balances.set(
+            legacy.token_address.clone(),
+            TokenBalance {
+                total_funds: legacy.total_funds,
+                remaining_balance: legacy.remaining_balance,
+            },
+        );
+
+        let mut payout_count: u32 = 0;
+        for record in legacy.payout_history.iter() {
+            payout_count = append_payout_record(
+                &env,
+                payout_count,
+                &PayoutRecord {
+                    recipient: record.recipient,
+                    token: legacy.token_address.clone(),
+                    amount: record.amount,
+                    fee: record.fee,
+                    timestamp: record.timestamp,
+                    prev_hash: record.prev_hash,
+                },
+            );
+        }
+
+        let program_data = ProgramData {
+            program_id: legacy.program_id,
+            balances,
+            authorized_payout_key: legacy.authorized_payout_key,
+            last_key_rotation: legacy.last_key_rotation,
+            payout_count,
+            chain_head: legacy.chain_head,
+            signers: legacy.signers,
+            threshold: legacy.threshold,
+            proposal_window_seconds: legacy.proposal_window_seconds,
+            next_proposal_id: legacy.next_proposal_id,
+            next_pending_payout_id: legacy.next_pending_payout_id,
+            fee_bps: legacy.fee_bps,
+            treasury: legacy.treasury,
+            total_fees_collected: legacy.total_fees_collected,
+            max_single_payout: None,
+            max_batch_total: None,
+            max_batch_recipients: None,
+            payout_nonce: 0,
+        };
+
+        env.storage().instance().set(&PROGRAM_DATA, &program_data);
+        env.storage().instance().set(&MIGRATED_MULTI_TOKEN, &true);
+        env.storage().instance().set(&MIGRATED_PAGINATED_HISTORY, &true);
+
+        Ok(program_data)
+    }
+
+    /// One-time migration for a program already on the multi-token
+    /// `balances` shape, from before `payout_history` moved from an
+    /// embedded `Vec` into per-index persistent storage: decodes the old
+    /// shape's `payout_history` and replays it into `DataKey::Payout`
+    /// entries, leaving every other field untouched.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    ///
+    /// # Returns
+    /// * `Ok(ProgramData)` - The migrated program data, now using `payout_count`
+    /// * `Err(Error::NotInitialized)` - Program not initialized
+    /// * `Err(Error::AlreadyMigrated)` - This program has already migrated
+    ///
+    /// # State Changes
+    /// - Writes one persistent entry per historical payout record
+    /// - Replaces the stored `ProgramData` with the paginated-history shape
+    /// - Sets a flag preventing this function from running again
+    ///
+    /// # Authorization
+    /// Requires `require_auth()` from the current `authorized_payout_key`
+    /// (the organizer), matching the gating used by `migrate_to_multi_token`.
+    ///
+    /// # Example
+    /// ```rust
+    /// // Run once against a multi-token program initialized before this upgrade
+    /// let migrated = escrow_client.migrate_payout_history();
+    /// let count = escrow_client.get_payout_count();
+    /// ```
+    pub fn migrate_payout_history(env: Env) -> Result<ProgramData, Error> {
+        if env.storage().instance().has(&MIGRATED_PAGINATED_HISTORY) {
+            return Err(Error::AlreadyMigrated);
+        }
+        if !env.storage().instance().has(&PROGRAM_DATA) {
+            return Err(Error::NotInitialized);
+        }
+
+        let legacy: LegacyProgramDataV2 = env.storage().instance().get(&PROGRAM_DATA).unwrap();
+        legacy.authorized_payout_key.require_auth();
+
+        let mut payout_count: u32 = 0;
+        for record in legacy.payout_history.iter() {
+            payout_count = append_payout_record(&env, payout_count, &record);
+        }
+
+        let program_data = ProgramData {
+            program_id: legacy.program_id,
+            balances: legacy.balances,
+            authorized_payout_key: legacy.authorized_payout_key,
+            last_key_rotation: legacy.last_key_rotation,
+            payout_count,
+            chain_head: legacy.chain_head,
+            signers: legacy.signers,
+            threshold: legacy.threshold,
+            proposal_window_seconds: legacy.proposal_window_seconds,
+            next_proposal_id: legacy.next_proposal_id,
+            next_pending_payout_id: legacy.next_pending_payout_id,
+            fee_bps: legacy.fee_bps,
+            treasury: legacy.treasury,
+            total_fees_collected: legacy.total_fees_collected,
+            max_single_payout: legacy.max_single_payout,
+            max_batch_total: legacy.max_batch_total,
+            max_batch_recipients: legacy.max_batch_recipients,
+            payout_nonce: legacy.payout_nonce,
+        };
+
+        env.storage().instance().set(&PROGRAM_DATA, &program_data);
+        env.storage().instance().set(&MIGRATED_PAGINATED_HISTORY, &true);
+
+        Ok(program_data)
     }
 }
\ No newline at end of file
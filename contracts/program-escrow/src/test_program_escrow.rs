@@ -0,0 +1,462 @@
+#![cfg(test)]
+
+use soroban_sdk::{testutils::Address as _, token, vec, Address, BytesN, Env, String};
+
+use crate::{Error, ProgramEscrowContract, ProgramEscrowContractClient};
+
+fn create_test_env() -> (Env, ProgramEscrowContractClient<'static>, Address) {
+    let env = Env::default();
+    let contract_id = env.register_contract(None, ProgramEscrowContract);
+    let client = ProgramEscrowContractClient::new(&env, &contract_id);
+
+    (env, client, contract_id)
+}
+
+fn create_token_contract<'a>(
+    e: &'a Env,
+    admin: &Address,
+) -> (Address, token::Client<'a>, token::StellarAssetClient<'a>) {
+    let token_id = e.register_stellar_asset_contract_v2(admin.clone());
+    let token = token_id.address();
+    let token_client = token::Client::new(e, &token);
+    let token_admin_client = token::StellarAssetClient::new(e, &token);
+    (token, token_client, token_admin_client)
+}
+
+// ========================================================================
+// Init / Fund Locking
+// ========================================================================
+
+#[test]
+fn test_init_program_and_lock_funds() {
+    let (env, client, contract_id) = create_test_env();
+    let backend = Address::generate(&env);
+    let signer1 = Address::generate(&env);
+    let signer2 = Address::generate(&env);
+    let treasury = Address::generate(&env);
+    let funder = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    let program_id = String::from_str(&env, "Hackathon2024");
+    let signers = vec![&env, signer1.clone(), signer2.clone()];
+    client.init_program(&program_id, &backend, &signers, &2, &86_400, &250, &treasury);
+
+    let (token, token_client, token_admin_client) = create_token_contract(&env, &funder);
+    token_admin_client.mint(&funder, &1000_0000000);
+    token_client.approve(&funder, &contract_id, &500_0000000, &1000);
+    client.lock_program_funds(&funder, &token, &500_0000000);
+
+    let balances = client.get_balances();
+    assert_eq!(balances.get(token).unwrap(), 500_0000000);
+
+    // Double initialization is rejected.
+    let result = client.try_init_program(&program_id, &backend, &signers, &2, &86_400, &250, &treasury);
+    assert_eq!(result, Err(Ok(Error::AlreadyInitialized)));
+}
+
+// ========================================================================
+// Quorum Payout Flow
+// ========================================================================
+
+#[test]
+fn test_propose_approve_execute_payout_then_claim() {
+    let (env, client, contract_id) = create_test_env();
+    let backend = Address::generate(&env);
+    let signer1 = Address::generate(&env);
+    let signer2 = Address::generate(&env);
+    let treasury = Address::generate(&env);
+    let funder = Address::generate(&env);
+    let winner = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    let program_id = String::from_str(&env, "Hackathon2024");
+    let signers = vec![&env, signer1.clone(), signer2.clone()];
+    client.init_program(&program_id, &backend, &signers, &2, &86_400, &250, &treasury);
+
+    let (token, token_client, token_admin_client) = create_token_contract(&env, &funder);
+    token_admin_client.mint(&funder, &1000_0000000);
+    token_client.approve(&funder, &contract_id, &1000_0000000, &1000);
+    client.lock_program_funds(&funder, &token, &1000_0000000);
+
+    let recipients = vec![&env, winner.clone()];
+    let amounts = vec![&env, 100_0000000i128];
+    let proposal_id = client.propose_payout(&signer1, &token, &recipients, &amounts);
+
+    // Single approval does not yet reach the 2-of-2 threshold.
+    let reached = client.approve_payout(&proposal_id, &signer1);
+    assert!(!reached);
+    let reached = client.approve_payout(&proposal_id, &signer2);
+    assert!(reached);
+
+    // Payout is queued behind the dispute window, not transferred yet.
+    let pending = client.get_pending_payout(&0);
+    assert_eq!(pending.recipient, winner);
+    assert!(!pending.claimed);
+
+    // Claiming before the dispute window elapses is rejected.
+    let result = client.try_claim_payout(&0, &winner);
+    assert_eq!(result, Err(Ok(Error::PendingPayoutNotReleased)));
+
+    env.ledger().set_timestamp(env.ledger().timestamp() + 259_201);
+    client.claim_payout(&0, &winner);
+
+    let winner_balance = token_client.balance(&winner);
+    // 2.5% protocol fee skimmed off the gross amount.
+    assert_eq!(winner_balance, 97_5000000);
+    assert_eq!(token_client.balance(&treasury), 2_5000000);
+
+    let history = client.get_payout_history(&0, &10);
+    assert_eq!(history.len(), 1);
+    assert!(client.verify_payout_chain(&history));
+}
+
+#[test]
+fn test_dispute_payout_returns_balance_and_blocks_claim() {
+    let (env, client, contract_id) = create_test_env();
+    let backend = Address::generate(&env);
+    let signer1 = Address::generate(&env);
+    let treasury = Address::generate(&env);
+    let funder = Address::generate(&env);
+    let winner = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    let program_id = String::from_str(&env, "Hackathon2024");
+    let signers = vec![&env, signer1.clone()];
+    client.init_program(&program_id, &backend, &signers, &1, &86_400, &0, &treasury);
+
+    let (token, token_client, token_admin_client) = create_token_contract(&env, &funder);
+    token_admin_client.mint(&funder, &1000_0000000);
+    token_client.approve(&funder, &contract_id, &1000_0000000, &1000);
+    client.lock_program_funds(&funder, &token, &1000_0000000);
+
+    let recipients = vec![&env, winner.clone()];
+    let amounts = vec![&env, 100_0000000i128];
+    let proposal_id = client.propose_payout(&signer1, &token, &recipients, &amounts);
+    client.approve_payout(&proposal_id, &signer1);
+
+    client.dispute_payout(&0);
+
+    let balances = client.get_balances();
+    assert_eq!(balances.get(token).unwrap(), 1000_0000000);
+
+    env.ledger().set_timestamp(env.ledger().timestamp() + 259_201);
+    let result = client.try_claim_payout(&0, &winner);
+    assert_eq!(result, Err(Ok(Error::PendingPayoutDisputed)));
+}
+
+// ========================================================================
+// Key Rotation
+// ========================================================================
+
+#[test]
+fn test_rotate_payout_key_respects_cooldown() {
+    let (env, client, _contract_id) = create_test_env();
+    let backend = Address::generate(&env);
+    let signer1 = Address::generate(&env);
+    let treasury = Address::generate(&env);
+    let new_backend = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    let program_id = String::from_str(&env, "Hackathon2024");
+    let signers = vec![&env, signer1.clone()];
+    client.init_program(&program_id, &backend, &signers, &1, &86_400, &0, &treasury);
+
+    let result = client.try_rotate_payout_key(&new_backend);
+    assert_eq!(result, Err(Ok(Error::RotationOnCooldown)));
+
+    env.ledger().set_timestamp(env.ledger().timestamp() + 86_401);
+    client.rotate_payout_key(&new_backend);
+
+    let info = client.get_program_info();
+    assert_eq!(info.authorized_payout_key, new_backend);
+}
+
+#[test]
+fn test_approve_key_rotation_recovers_after_revoke() {
+    let (env, client, _contract_id) = create_test_env();
+    let backend = Address::generate(&env);
+    let signer1 = Address::generate(&env);
+    let signer2 = Address::generate(&env);
+    let treasury = Address::generate(&env);
+    let new_backend = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    let program_id = String::from_str(&env, "Hackathon2024");
+    let signers = vec![&env, signer1.clone(), signer2.clone()];
+    client.init_program(&program_id, &backend, &signers, &2, &86_400, &0, &treasury);
+
+    // Key is revoked (e.g. suspected leak); it can no longer authorize its
+    // own rotation.
+    client.revoke_payout_key();
+
+    // The quorum can still recover it, and isn't gated by the cooldown
+    // rotate_payout_key would otherwise enforce.
+    let proposal_id = client.propose_key_rotation(&signer1, &Some(new_backend.clone()));
+    let reached = client.approve_key_rotation(&proposal_id, &signer1);
+    assert!(!reached);
+    let reached = client.approve_key_rotation(&proposal_id, &signer2);
+    assert!(reached);
+
+    let info = client.get_program_info();
+    assert_eq!(info.authorized_payout_key, new_backend);
+
+    // A second approval on the same (now-executed) proposal is rejected.
+    let result = client.try_approve_key_rotation(&proposal_id, &signer1);
+    assert_eq!(result, Err(Ok(Error::ProposalAlreadyExecuted)));
+}
+
+// ========================================================================
+// Vesting
+// ========================================================================
+
+#[test]
+fn test_schedule_vested_payout_and_claim_vested() {
+    let (env, client, contract_id) = create_test_env();
+    let backend = Address::generate(&env);
+    let signer1 = Address::generate(&env);
+    let treasury = Address::generate(&env);
+    let funder = Address::generate(&env);
+    let grantee = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    let program_id = String::from_str(&env, "Grant2024");
+    let signers = vec![&env, signer1.clone()];
+    client.init_program(&program_id, &backend, &signers, &1, &86_400, &0, &treasury);
+
+    let (token, token_client, token_admin_client) = create_token_contract(&env, &funder);
+    token_admin_client.mint(&funder, &1000_0000000);
+    token_client.approve(&funder, &contract_id, &300_0000000, &1000);
+    client.lock_program_funds(&funder, &token, &300_0000000);
+
+    let release_ats = vec![&env, 100u64, 200u64];
+    let amounts = vec![&env, 100_0000000i128, 200_0000000i128];
+    client.schedule_vested_payout(&grantee, &token, &300_0000000, &release_ats, &amounts);
+
+    // Before the first tranche is due, nothing releases.
+    env.ledger().set_timestamp(50);
+    let released = client.claim_vested(&grantee, &grantee);
+    assert_eq!(released, 0);
+
+    // First tranche due, second still locked.
+    env.ledger().set_timestamp(150);
+    let released = client.claim_vested(&grantee, &grantee);
+    assert_eq!(released, 100_0000000);
+    assert_eq!(token_client.balance(&grantee), 100_0000000);
+
+    // Second tranche due.
+    env.ledger().set_timestamp(250);
+    let released = client.claim_vested(&grantee, &grantee);
+    assert_eq!(released, 200_0000000);
+    assert_eq!(token_client.balance(&grantee), 300_0000000);
+
+    let schedule = client.get_vested_schedule(&grantee);
+    assert!(schedule.tranches.iter().all(|t| t.claimed));
+}
+
+mod reentrant_claim_payout_token {
+    use soroban_sdk::{contract, contractimpl, Address, Env, String};
+
+    /// A malicious "token" that calls back into `claim_payout` from within
+    /// its own `transfer`, verifying that a reentrant `claim_payout` call is
+    /// rejected.
+    #[contract]
+    pub struct ReentrantClaimPayoutToken;
+
+    #[contractimpl]
+    impl ReentrantClaimPayoutToken {
+        pub fn transfer_from(_env: Env, _spender: Address, _from: Address, _to: Address, _amount: i128) {}
+
+        pub fn transfer(env: Env, _from: Address, _to: Address, _amount: i128) {
+            let escrow_id: Address = env
+                .storage()
+                .instance()
+                .get(&String::from_str(&env, "escrow"))
+                .unwrap();
+            let pending_payout_id: u64 = env
+                .storage()
+                .instance()
+                .get(&String::from_str(&env, "pending"))
+                .unwrap();
+            let claimant: Address = env
+                .storage()
+                .instance()
+                .get(&String::from_str(&env, "claimant"))
+                .unwrap();
+
+            let client = crate::ProgramEscrowContractClient::new(&env, &escrow_id);
+            let result = client.try_claim_payout(&pending_payout_id, &claimant);
+            assert!(result.is_err(), "reentrant claim_payout call should fail");
+        }
+
+        pub fn set_callback(env: Env, escrow: Address, pending_payout_id: u64, claimant: Address) {
+            env.storage()
+                .instance()
+                .set(&String::from_str(&env, "escrow"), &escrow);
+            env.storage()
+                .instance()
+                .set(&String::from_str(&env, "pending"), &pending_payout_id);
+            env.storage()
+                .instance()
+                .set(&String::from_str(&env, "claimant"), &claimant);
+        }
+    }
+}
+
+#[test]
+fn test_claim_payout_rejects_reentrant_call_from_token_transfer() {
+    use reentrant_claim_payout_token::ReentrantClaimPayoutToken;
+
+    let (env, client, contract_id) = create_test_env();
+    let backend = Address::generate(&env);
+    let signer1 = Address::generate(&env);
+    let treasury = Address::generate(&env);
+    let funder = Address::generate(&env);
+    let winner = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    let program_id = String::from_str(&env, "Hackathon2024");
+    let signers = vec![&env, signer1.clone()];
+    client.init_program(&program_id, &backend, &signers, &1, &86_400, &0, &treasury);
+
+    let token_id = env.register_contract(None, ReentrantClaimPayoutToken);
+    let token_client =
+        reentrant_claim_payout_token::ReentrantClaimPayoutTokenClient::new(&env, &token_id);
+    token_client.set_callback(&contract_id, &0, &winner);
+
+    client.lock_program_funds(&funder, &token_id, &1000_0000000);
+
+    let recipients = vec![&env, winner.clone()];
+    let amounts = vec![&env, 100_0000000i128];
+    let proposal_id = client.propose_payout(&signer1, &token_id, &recipients, &amounts);
+    client.approve_payout(&proposal_id, &signer1);
+
+    env.ledger().set_timestamp(env.ledger().timestamp() + 259_201);
+
+    // The reentrant call made from within the token's `transfer` is
+    // rejected, because `claim_payout` persists the entry as claimed (while
+    // holding the guard) before transferring, so the outer call still
+    // completes and leaves exactly one claim recorded.
+    client.claim_payout(&0, &winner);
+    let history = client.get_payout_history(&0, &10);
+    assert_eq!(history.len(), 1);
+}
+
+// ========================================================================
+// Idempotent Backend Payouts
+// ========================================================================
+
+#[test]
+fn test_batch_payout_idempotent_retry_is_safe_noop() {
+    let (env, client, contract_id) = create_test_env();
+    let backend = Address::generate(&env);
+    let signer1 = Address::generate(&env);
+    let treasury = Address::generate(&env);
+    let funder = Address::generate(&env);
+    let winner = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    let program_id = String::from_str(&env, "Hackathon2024");
+    let signers = vec![&env, signer1.clone()];
+    client.init_program(&program_id, &backend, &signers, &1, &86_400, &0, &treasury);
+
+    let (token, token_client, token_admin_client) = create_token_contract(&env, &funder);
+    token_admin_client.mint(&funder, &1000_0000000);
+    token_client.approve(&funder, &contract_id, &1000_0000000, &1000);
+    client.lock_program_funds(&funder, &token, &1000_0000000);
+
+    let request_id = BytesN::from_array(&env, &[7u8; 32]);
+    let recipients = vec![&env, winner.clone()];
+    let amounts = vec![&env, 50_0000000i128];
+
+    client.batch_payout_idempotent(&backend, &request_id, &token, &0, &recipients, &amounts);
+    assert_eq!(token_client.balance(&winner), 50_0000000);
+
+    // A retry with the same request_id must stay a safe no-op, even though
+    // payout_nonce has already advanced past the nonce this call was
+    // originally signed with - this is the scenario chunk4-4 broke.
+    client.batch_payout_idempotent(&backend, &request_id, &token, &0, &recipients, &amounts);
+    assert_eq!(token_client.balance(&winner), 50_0000000);
+
+    // A genuinely new request must use the advanced nonce.
+    let next_request_id = BytesN::from_array(&env, &[8u8; 32]);
+    let result = client.try_batch_payout_idempotent(&backend, &next_request_id, &token, &0, &recipients, &amounts);
+    assert_eq!(result, Err(Ok(Error::InvalidNonce)));
+
+    client.batch_payout_idempotent(&backend, &next_request_id, &token, &1, &recipients, &amounts);
+    assert_eq!(token_client.balance(&winner), 100_0000000);
+}
+
+// ========================================================================
+// Multi-Token Accounting
+// ========================================================================
+
+#[test]
+fn test_multi_token_balances_are_tracked_independently() {
+    let (env, client, contract_id) = create_test_env();
+    let backend = Address::generate(&env);
+    let signer1 = Address::generate(&env);
+    let treasury = Address::generate(&env);
+    let funder = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    let program_id = String::from_str(&env, "Hackathon2024");
+    let signers = vec![&env, signer1.clone()];
+    client.init_program(&program_id, &backend, &signers, &1, &86_400, &0, &treasury);
+
+    let (usdc, usdc_client, usdc_admin) = create_token_contract(&env, &funder);
+    let (xlm, xlm_client, xlm_admin) = create_token_contract(&env, &funder);
+    usdc_admin.mint(&funder, &1000_0000000);
+    xlm_admin.mint(&funder, &2000_0000000);
+
+    usdc_client.approve(&funder, &contract_id, &400_0000000, &1000);
+    client.lock_program_funds(&funder, &usdc, &400_0000000);
+    xlm_client.approve(&funder, &contract_id, &900_0000000, &1000);
+    client.lock_program_funds(&funder, &xlm, &900_0000000);
+
+    assert_eq!(client.get_remaining_balance_for(&usdc), 400_0000000);
+    assert_eq!(client.get_remaining_balance_for(&xlm), 900_0000000);
+
+    let balances = client.get_balances();
+    assert_eq!(balances.len(), 2);
+}
+
+// ========================================================================
+// Payout Limits
+// ========================================================================
+
+#[test]
+fn test_set_payout_limits_rejects_oversized_proposal() {
+    let (env, client, contract_id) = create_test_env();
+    let backend = Address::generate(&env);
+    let signer1 = Address::generate(&env);
+    let treasury = Address::generate(&env);
+    let funder = Address::generate(&env);
+    let winner = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    let program_id = String::from_str(&env, "Hackathon2024");
+    let signers = vec![&env, signer1.clone()];
+    client.init_program(&program_id, &backend, &signers, &1, &86_400, &0, &treasury);
+
+    let (token, token_client, token_admin_client) = create_token_contract(&env, &funder);
+    token_admin_client.mint(&funder, &1000_0000000);
+    token_client.approve(&funder, &contract_id, &1000_0000000, &1000);
+    client.lock_program_funds(&funder, &token, &1000_0000000);
+
+    client.set_payout_limits(&Some(50_0000000i128), &None, &None);
+
+    let recipients = vec![&env, winner.clone()];
+    let amounts = vec![&env, 100_0000000i128];
+    let result = client.try_propose_payout(&signer1, &token, &recipients, &amounts);
+    assert_eq!(result, Err(Ok(Error::PayoutLimitExceeded)));
+}